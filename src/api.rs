@@ -1,4 +1,5 @@
 use std::future::Future;
+use std::sync::Arc;
 use std::time::Duration;
 
 use reqwest::{RequestBuilder, Response};
@@ -8,14 +9,40 @@ use tokio::time::sleep;
 use crate::endpoints::collection::CollectionApi;
 use crate::escape_xml::escape_xml;
 use crate::{
-    ApiXmlErrors, CollectionItem, CollectionItemBrief, Error, HotListApi, Result, SearchApi,
+    ApiXmlErrors, CacheKey, CollectionEditingApi, CollectionItem, CollectionItemBrief, Error,
+    FamilyApi, ForumApi, ForumListApi, GeekListApi, GuildApi, HotListApi, LoginCredentials,
+    LoginRequest, LoginSession, PlayLoggingApi, PlaysApi, RateLimiter, ResponseCache, Result,
+    RetryPolicy, SearchApi, ThingApi, ThreadApi, UserApi,
 };
 
 /// API for making requests to the [Board Game Geek API](https://boardgamegeek.com/wiki/page/BGG_XML_API2).
+///
+/// Cloning the API is cheap, and clones share the same HTTP client and rate limiter.
+#[derive(Clone)]
 pub struct BoardGameGeekApi {
     // URL for the board game geek API.
     // Note this is a String instead of a 'static &str for unit test purposes.
     pub(crate) base_url: String,
+    // URL for the legacy version of the board game geek API, which some endpoints,
+    // such as geeklists, are only available on.
+    pub(crate) legacy_base_url: String,
+    // URL for the main site, which is used for logging in.
+    pub(crate) site_base_url: String,
+    // Token sent as a bearer token in the authorization header of every request,
+    // required by the API for registered applications.
+    pub(crate) auth_token: Option<String>,
+    // The session of the logged in user, if any, whose cookies are sent with every request.
+    pub(crate) session: Option<LoginSession>,
+    // User agent sent in the header of every request, if set.
+    pub(crate) user_agent: Option<String>,
+    // Timeout applied to every request, if set.
+    pub(crate) timeout: Option<Duration>,
+    // Limits the rate requests are sent at, if set. Shared between clones of the API.
+    pub(crate) rate_limiter: Option<Arc<RateLimiter>>,
+    // Controls how requests are retried when the API asks for them to be tried again later.
+    pub(crate) retry_policy: RetryPolicy,
+    // Cache of responses from the API, if set. Shared between clones of the API.
+    pub(crate) cache: Option<Arc<ResponseCache>>,
     // Http client for making requests.
     pub(crate) client: reqwest::Client,
 }
@@ -28,39 +55,188 @@ impl Default for BoardGameGeekApi {
 
 impl BoardGameGeekApi {
     const BASE_URL: &'static str = "https://boardgamegeek.com/xmlapi2";
+    const LEGACY_BASE_URL: &'static str = "https://boardgamegeek.com/xmlapi";
+    const SITE_BASE_URL: &'static str = "https://boardgamegeek.com";
 
     /// Creates a new API.
     pub fn new() -> Self {
         Self {
             base_url: String::from(BoardGameGeekApi::BASE_URL),
+            legacy_base_url: String::from(BoardGameGeekApi::LEGACY_BASE_URL),
+            site_base_url: String::from(BoardGameGeekApi::SITE_BASE_URL),
+            auth_token: None,
+            session: None,
+            user_agent: None,
+            timeout: None,
+            rate_limiter: None,
+            retry_policy: RetryPolicy::default(),
+            cache: None,
             client: reqwest::Client::new(),
         }
     }
 
+    /// Creates a builder for configuring the API, such as the HTTP client it uses, or
+    /// the base URL requests are made to.
+    pub fn builder() -> BoardGameGeekApiBuilder {
+        BoardGameGeekApiBuilder::new()
+    }
+
+    /// Logs in to Board Game Geek as the given user. The returned session cookies are
+    /// kept and sent with every subsequent request, so that private information, such
+    /// as the private info in the user's own collection, can be requested.
+    ///
+    /// Returns [Error::UnauthorisedError] if the username or password is incorrect.
+    pub async fn login(&mut self, username: &str, password: &str) -> Result<()> {
+        let body = LoginRequest {
+            credentials: LoginCredentials { username, password },
+        };
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        let response = self
+            .configure(
+                self.client
+                    .post(format!("{}/login/api/v1", self.site_base_url)),
+            )
+            .json(&body)
+            .send()
+            .await?;
+        let response = match response.error_for_status() {
+            Err(e)
+                if e.status() == Some(reqwest::StatusCode::BAD_REQUEST)
+                    || e.status() == Some(reqwest::StatusCode::UNAUTHORIZED)
+                    || e.status() == Some(reqwest::StatusCode::FORBIDDEN) =>
+            {
+                return Err(Error::UnauthorisedError(e));
+            }
+            Err(e) => return Err(Error::HttpError(e)),
+            Ok(response) => response,
+        };
+
+        match LoginSession::from_headers(username, response.headers()) {
+            Some(session) => {
+                self.session = Some(session);
+                Ok(())
+            }
+            None => Err(Error::InvalidResponseError(
+                "no session cookies returned from login".to_string(),
+            )),
+        }
+    }
+
+    /// Logs out of the current session, if any, so that no session cookies are sent
+    /// with future requests.
+    pub fn logout(&mut self) {
+        self.session = None;
+    }
+
+    /// Returns the current login session, if the user has logged in.
+    pub fn session(&self) -> Option<&LoginSession> {
+        self.session.as_ref()
+    }
+
+    /// Returns the cache of responses from the API, if one was set when building the API.
+    /// This can be used to invalidate cached responses.
+    pub fn cache(&self) -> Option<&ResponseCache> {
+        self.cache.as_deref()
+    }
+
+    /// Whether a user is logged in, and their session has not yet expired.
+    pub fn is_logged_in(&self) -> bool {
+        self.session.as_ref().is_some_and(LoginSession::is_valid)
+    }
+
     /// Returns the collection endpoint of the API, which is used for querying a specific
     /// user's board game collection.
-    pub fn collection(&self) -> CollectionApi<CollectionItem> {
+    pub fn collection(&self) -> CollectionApi<'_, CollectionItem> {
         CollectionApi::new(self)
     }
 
     /// Returns the collection endpoint of the API, which is used for querying a specific
     /// user's board game collection.
-    pub fn collection_brief(&self) -> CollectionApi<CollectionItemBrief> {
+    pub fn collection_brief(&self) -> CollectionApi<'_, CollectionItemBrief> {
         CollectionApi::new(self)
     }
 
+    /// Returns the collection editing endpoint of the site, which is used for adding games
+    /// to the logged in user's collection, and updating the games already in it.
+    pub fn collection_editing(&self) -> CollectionEditingApi<'_> {
+        CollectionEditingApi::new(self)
+    }
+
+    /// Returns the family endpoint of the API, which is used for getting families of
+    /// related items, such as a series of games, by their ID.
+    pub fn family(&self) -> FamilyApi<'_> {
+        FamilyApi::new(self)
+    }
+
+    /// Returns the forum endpoint of the API, which is used for getting the threads
+    /// in a forum.
+    pub fn forum(&self) -> ForumApi<'_> {
+        ForumApi::new(self)
+    }
+
+    /// Returns the forum list endpoint of the API, which is used for getting the forums
+    /// attached to a game or a family.
+    pub fn forum_list(&self) -> ForumListApi<'_> {
+        ForumListApi::new(self)
+    }
+
+    /// Returns the geeklist endpoint of the API, which is used for getting user created
+    /// lists of games and other items. This uses the legacy version of the API, as
+    /// geeklists are not available on the current version.
+    pub fn geeklist(&self) -> GeekListApi<'_> {
+        GeekListApi::new(self)
+    }
+
+    /// Returns the guild endpoint of the API, which is used for getting the details of
+    /// a guild, along with its members.
+    pub fn guild(&self) -> GuildApi<'_> {
+        GuildApi::new(self)
+    }
+
     /// Returns the hot list endpoint of the API, which is used for querying the current
     /// trending board games.
-    pub fn hot_list(&self) -> HotListApi {
+    pub fn hot_list(&self) -> HotListApi<'_> {
         HotListApi::new(self)
     }
 
+    /// Returns the play logging endpoint of the site, which is used for logging, editing
+    /// and deleting the plays of the logged in user.
+    pub fn play_logging(&self) -> PlayLoggingApi<'_> {
+        PlayLoggingApi::new(self)
+    }
+
+    /// Returns the plays endpoint of the API, which is used for getting the plays
+    /// logged by a user, or the plays logged of a particular game.
+    pub fn plays(&self) -> PlaysApi<'_> {
+        PlaysApi::new(self)
+    }
+
     /// Returns the search endpoint of the API, which is used for searching for board games
     /// by name.
-    pub fn search(&self) -> SearchApi {
+    pub fn search(&self) -> SearchApi<'_> {
         SearchApi::new(self)
     }
 
+    /// Returns the thing endpoint of the API, which is used for getting the full details
+    /// of board games and expansions by their ID.
+    pub fn thing(&self) -> ThingApi<'_> {
+        ThingApi::new(self)
+    }
+
+    /// Returns the thread endpoint of the API, which is used for getting the articles
+    /// posted in a forum thread.
+    pub fn thread(&self) -> ThreadApi<'_> {
+        ThreadApi::new(self)
+    }
+
+    /// Returns the user endpoint of the API, which is used for getting a user's profile,
+    /// along with their buddies, guilds, and personal top and hot lists.
+    pub fn user(&self) -> UserApi<'_> {
+        UserApi::new(self)
+    }
+
     // Creates a reqwest::RequestBuilder from the base url and the provided
     // endpoint and query.
     pub(crate) fn build_request(
@@ -68,9 +244,62 @@ impl BoardGameGeekApi {
         endpoint: &str,
         query: &[(&str, String)],
     ) -> reqwest::RequestBuilder {
-        self.client
-            .get(format!("{}/{}", self.base_url, endpoint))
-            .query(query)
+        self.authorise(
+            self.client
+                .get(format!("{}/{}", self.base_url, endpoint))
+                .query(query),
+        )
+    }
+
+    // Creates a reqwest::RequestBuilder from the legacy base url and the provided
+    // endpoint and query, for endpoints only available on the legacy version of the API.
+    pub(crate) fn build_legacy_request(
+        &self,
+        endpoint: &str,
+        query: &[(&str, String)],
+    ) -> reqwest::RequestBuilder {
+        self.authorise(
+            self.client
+                .get(format!("{}/{}", self.legacy_base_url, endpoint))
+                .query(query),
+        )
+    }
+
+    // Creates a reqwest::RequestBuilder for posting to the provided endpoint on the main
+    // site, for endpoints that change a user's data, and so require them to be logged in.
+    pub(crate) fn build_site_request(&self, endpoint: &str) -> Result<RequestBuilder> {
+        if !self.is_logged_in() {
+            return Err(Error::NotLoggedInError);
+        }
+        Ok(self.authorise(
+            self.client
+                .post(format!("{}/{}", self.site_base_url, endpoint)),
+        ))
+    }
+
+    // Adds the auth token to a request as a bearer token, if one has been set, along
+    // with the cookies of the logged in session, if any.
+    fn authorise(&self, request: RequestBuilder) -> RequestBuilder {
+        let request = match &self.auth_token {
+            Some(auth_token) => self.configure(request).bearer_auth(auth_token),
+            None => self.configure(request),
+        };
+        match &self.session {
+            Some(session) => request.header(reqwest::header::COOKIE, session.cookie_header()),
+            None => request,
+        }
+    }
+
+    // Adds the user agent and timeout to a request, if they have been set.
+    fn configure(&self, request: RequestBuilder) -> RequestBuilder {
+        let request = match &self.user_agent {
+            Some(user_agent) => request.header(reqwest::header::USER_AGENT, user_agent),
+            None => request,
+        };
+        match self.timeout {
+            Some(timeout) => request.timeout(timeout),
+            None => request,
+        }
     }
 
     // Handles a HTTP request by calling execute_request_raw, then parses the response
@@ -79,8 +308,16 @@ impl BoardGameGeekApi {
         &self,
         request: RequestBuilder,
     ) -> Result<T> {
-        let response = self.send_request(request).await?;
-        let response_text = response.text().await?;
+        let cache_key = self.cache_key(&request);
+        let cached = match (&self.cache, &cache_key) {
+            (Some(cache), Some((_, key))) => cache.get(key),
+            _ => None,
+        };
+        let is_cached = cached.is_some();
+        let response_text = match cached {
+            Some(response_text) => response_text,
+            None => self.send_request(request).await?.text().await?,
+        };
 
         // The API doesn't sanitise string values such as the names and descriptions.
         // So we must escape the & chars to stop this parsing from erroring on any
@@ -89,7 +326,15 @@ impl BoardGameGeekApi {
 
         let parse_result = from_str(&escaped);
         match parse_result {
-            Ok(result) => Ok(result),
+            Ok(result) => {
+                // Only responses that parse are cached, so errors are always retried.
+                if let (Some(cache), Some((endpoint, key)), false) =
+                    (&self.cache, cache_key, is_cached)
+                {
+                    cache.insert(&endpoint, key, response_text);
+                }
+                Ok(result)
+            }
             Err(e) => {
                 // The API returns a 200 but with an XML error in some cases,
                 // such as a usename not found, so we try to parse that first
@@ -105,33 +350,82 @@ impl BoardGameGeekApi {
         }
     }
 
+    // Gets the endpoint of a request, and the key to cache its response by, made up of the
+    // full URL and the logged in user. None if there is no cache, or the request can't be
+    // cached.
+    fn cache_key(&self, request: &RequestBuilder) -> Option<(String, CacheKey)> {
+        self.cache.as_ref()?;
+        let request = request.try_clone()?.build().ok()?;
+        if request.method() != reqwest::Method::GET {
+            return None;
+        }
+        let url = request.url().as_str();
+        let relative = url
+            .strip_prefix(self.base_url.as_str())
+            .or_else(|| url.strip_prefix(self.legacy_base_url.as_str()))?;
+        // The endpoint is the first part of the path, without any ID or query after it.
+        let endpoint = relative.trim_start_matches('/').split(['/', '?']).next()?;
+        let key = CacheKey {
+            username: self
+                .session
+                .as_ref()
+                .map(|session| session.username.clone()),
+            url: url.to_string(),
+        };
+        Some((endpoint.to_string(), key))
+    }
+
     // Handles an HTTP request. send_request accepts a reqwest::ReqwestBuilder,
-    // sends it and awaits. If the response is Accepted (202), it will wait for the data to
-    // be ready and try again. Any errors are wrapped in the local BoardGameGeekApiError
-    // enum before being returned.
-    fn send_request(&self, request: RequestBuilder) -> impl Future<Output = Result<Response>> {
+    // sends it and awaits. If the response has a status that the retry policy retries,
+    // such as Accepted (202) while the data isn't ready yet, it will wait and try again.
+    // An Accepted response that is not retried is returned as a MaxRetryError.
+    // Any errors are wrapped in the local BoardGameGeekApiError enum before being returned.
+    pub(crate) fn send_request(
+        &self,
+        request: RequestBuilder,
+    ) -> impl Future<Output = Result<Response>> {
         let mut retries: u32 = 0;
+        let mut total_wait = Duration::ZERO;
+        let rate_limiter = self.rate_limiter.clone();
+        let retry_policy = self.retry_policy.clone();
+        // Requests that change data, such as logging a play, are never retried, as the
+        // change may have been saved before the error was returned.
+        let retryable = request
+            .try_clone()
+            .and_then(|request| request.build().ok())
+            .is_some_and(|request| request.method().is_idempotent());
         async move {
             loop {
                 let request_clone = request.try_clone().expect("Couldn't clone request");
+                if let Some(rate_limiter) = &rate_limiter {
+                    rate_limiter.acquire().await;
+                }
                 let response = match request_clone.send().await {
                     Ok(response) => response,
                     Err(e) => break Err(Error::HttpError(e)),
                 };
-                if response.status() == reqwest::StatusCode::ACCEPTED {
-                    // Attempt the request 5 times total
-                    if retries >= 4 {
-                        break Err(Error::MaxRetryError(retries));
+                if retryable && retry_policy.retries_status(response.status()) {
+                    if let Some(delay) =
+                        retry_policy.next_delay(retries, total_wait, response.headers())
+                    {
+                        retries += 1;
+                        total_wait += delay;
+                        sleep(delay).await;
+                        continue;
                     }
-                    // Request has been accepted but the data isn't ready yet, we wait a short amount of time
-                    // before trying again, with exponential backoff.
-                    let backoff_multiplier = 2_u64.pow(retries);
-                    retries += 1;
-                    let delay = Duration::from_millis(200 * backoff_multiplier);
-                    sleep(delay).await;
-                    continue;
+                }
+                // Accepted isn't an error status, but the data still isn't ready, so it
+                // must never be parsed as the response whether it was retried or not.
+                if response.status() == reqwest::StatusCode::ACCEPTED {
+                    break Err(Error::MaxRetryError(retries));
                 }
                 break match response.error_for_status() {
+                    Err(e)
+                        if e.status() == Some(reqwest::StatusCode::UNAUTHORIZED)
+                            || e.status() == Some(reqwest::StatusCode::FORBIDDEN) =>
+                    {
+                        Err(Error::UnauthorisedError(e))
+                    }
                     Err(e) => Err(Error::HttpError(e)),
                     Ok(res) => Ok(res),
                 };
@@ -140,17 +434,170 @@ impl BoardGameGeekApi {
     }
 }
 
+/// Builder for configuring a [BoardGameGeekApi], created with [BoardGameGeekApi::builder].
+///
+/// Any options not set are left as the defaults used by [BoardGameGeekApi::new].
+#[derive(Clone, Debug, Default)]
+pub struct BoardGameGeekApiBuilder {
+    /// The base URL of the API.
+    base_url: Option<String>,
+    /// The base URL of the legacy version of the API.
+    legacy_base_url: Option<String>,
+    /// The base URL of the main site.
+    site_base_url: Option<String>,
+    /// Token to authenticate requests with.
+    auth_token: Option<String>,
+    /// Http client to make requests with.
+    client: Option<reqwest::Client>,
+    /// User agent to send with requests.
+    user_agent: Option<String>,
+    /// Timeout for each request.
+    timeout: Option<Duration>,
+    /// Timeout for connecting to the API.
+    connect_timeout: Option<Duration>,
+    /// Limiter for the rate requests are sent at.
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// Policy for retrying requests.
+    retry_policy: Option<RetryPolicy>,
+    /// Cache of responses.
+    cache: Option<Arc<ResponseCache>>,
+}
+
+impl BoardGameGeekApiBuilder {
+    /// Constructs a new builder with all options set to None.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the base_url field, for the URL of the API that requests are made to. Such
+    /// as a local server to test against.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Sets the legacy_base_url field, for the URL of the legacy version of the API,
+    /// which requests to endpoints such as geeklists are made to.
+    pub fn legacy_base_url(mut self, legacy_base_url: impl Into<String>) -> Self {
+        self.legacy_base_url = Some(legacy_base_url.into());
+        self
+    }
+
+    /// Sets the site_base_url field, for the URL of the main site, which requests to log
+    /// in and to change a user's data are made to.
+    pub fn site_base_url(mut self, site_base_url: impl Into<String>) -> Self {
+        self.site_base_url = Some(site_base_url.into());
+        self
+    }
+
+    /// Sets the auth_token field, so that every request is authenticated with the token.
+    ///
+    /// Applications registered with Board Game Geek are issued a token, which must be
+    /// sent with requests made to the API.
+    pub fn auth_token(mut self, auth_token: impl Into<String>) -> Self {
+        self.auth_token = Some(auth_token.into());
+        self
+    }
+
+    /// Sets the client field, for the HTTP client used to make requests.
+    ///
+    /// The connect timeout has no effect when a client is set, and should be set on
+    /// the client instead.
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the user_agent field, for the user agent sent in the header of every request.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Sets the timeout field, for how long to wait for each request to complete before
+    /// returning an error.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the connect_timeout field, for how long to wait to connect to the API before
+    /// returning an error.
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Sets the rate_limiter field, so that requests are spaced out to avoid being
+    /// throttled by the API. The limiter is shared by all clones of the built API.
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(Arc::new(rate_limiter));
+        self
+    }
+
+    /// Sets the retry_policy field, for how requests are retried when the API responds
+    /// asking for them to be tried again later.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Sets the cache field, so that responses are cached and repeated requests for
+    /// the same data skip the network. The cache is shared by all clones of the built API.
+    pub fn cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// Builds the API from the options set. Returns an error if the HTTP client could
+    /// not be created.
+    pub fn build(self) -> Result<BoardGameGeekApi> {
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                let mut client_builder = reqwest::Client::builder();
+                if let Some(connect_timeout) = self.connect_timeout {
+                    client_builder = client_builder.connect_timeout(connect_timeout);
+                }
+                client_builder.build()?
+            }
+        };
+        Ok(BoardGameGeekApi {
+            base_url: self
+                .base_url
+                .unwrap_or_else(|| String::from(BoardGameGeekApi::BASE_URL)),
+            legacy_base_url: self
+                .legacy_base_url
+                .unwrap_or_else(|| String::from(BoardGameGeekApi::LEGACY_BASE_URL)),
+            site_base_url: self
+                .site_base_url
+                .unwrap_or_else(|| String::from(BoardGameGeekApi::SITE_BASE_URL)),
+            auth_token: self.auth_token,
+            session: None,
+            user_agent: self.user_agent,
+            timeout: self.timeout,
+            rate_limiter: self.rate_limiter,
+            retry_policy: self.retry_policy.unwrap_or_default(),
+            cache: self.cache,
+            client,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use mockito::Matcher;
+
     use super::*;
 
     #[tokio::test]
     async fn send_request() {
         let mut server = mockito::Server::new_async().await;
-        let api = BoardGameGeekApi {
-            base_url: server.url(),
-            client: reqwest::Client::new(),
-        };
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .site_base_url(server.url())
+            .build()
+            .unwrap();
 
         let mock = server
             .mock("GET", "/some_endpoint")
@@ -170,10 +617,11 @@ mod tests {
     #[tokio::test]
     async fn send_failed_request() {
         let mut server = mockito::Server::new_async().await;
-        let api = BoardGameGeekApi {
-            base_url: server.url(),
-            client: reqwest::Client::new(),
-        };
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .site_base_url(server.url())
+            .build()
+            .unwrap();
 
         let mock = server
             .mock("GET", "/some_endpoint")
@@ -188,13 +636,462 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[tokio::test]
+    async fn send_request_with_auth_token() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .site_base_url(server.url())
+            .auth_token("some_token")
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/some_endpoint")
+            .match_header("authorization", "Bearer some_token")
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let req = api.build_request("some_endpoint", &[]);
+        let res = api.send_request(req).await;
+
+        mock.assert_async().await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn send_unauthorised_request() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .site_base_url(server.url())
+            .auth_token("bad_token")
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/some_endpoint")
+            .with_status(401)
+            .create_async()
+            .await;
+
+        let req = api.build_request("some_endpoint", &[]);
+        let res = api.send_request(req).await;
+
+        mock.assert_async().await;
+        assert!(matches!(res, Err(Error::UnauthorisedError(_))));
+    }
+
+    #[tokio::test]
+    async fn login() {
+        let mut server = mockito::Server::new_async().await;
+        let mut api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .site_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let login_mock = server
+            .mock("POST", "/login/api/v1")
+            .match_body(Matcher::JsonString(
+                r#"{"credentials":{"username":"somename","password":"somepassword"}}"#.into(),
+            ))
+            .with_status(204)
+            .with_header(
+                "set-cookie",
+                "bggusername=somename; Max-Age=2592000; path=/",
+            )
+            .with_header("set-cookie", "bggpassword=abc123; Max-Age=2592000; path=/")
+            .with_header("set-cookie", "SessionID=xyz789; path=/; HttpOnly")
+            .create_async()
+            .await;
+
+        let res = api.login("somename", "somepassword").await;
+        login_mock.assert_async().await;
+        assert!(res.is_ok(), "error returned when okay expected");
+        assert!(api.is_logged_in());
+        assert_eq!(api.session().unwrap().username, "somename");
+
+        let mock = server
+            .mock("GET", "/some_endpoint")
+            .match_header(
+                "cookie",
+                "bggusername=somename; bggpassword=abc123; SessionID=xyz789",
+            )
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let req = api.build_request("some_endpoint", &[]);
+        let res = api.send_request(req).await;
+        mock.assert_async().await;
+        assert!(res.is_ok());
+
+        api.logout();
+        assert!(!api.is_logged_in());
+    }
+
+    #[tokio::test]
+    async fn login_invalid_credentials() {
+        let mut server = mockito::Server::new_async().await;
+        let mut api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .site_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("POST", "/login/api/v1")
+            .with_status(400)
+            .with_body(r#"{"errors":{"message":"Invalid Username or Password"}}"#)
+            .create_async()
+            .await;
+
+        let res = api.login("somename", "wrongpassword").await;
+        mock.assert_async().await;
+        assert!(matches!(res, Err(Error::UnauthorisedError(_))));
+        assert!(!api.is_logged_in());
+    }
+
+    #[tokio::test]
+    async fn builder_user_agent() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .client(reqwest::Client::new())
+            .user_agent("some-app/1.0")
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/some_endpoint")
+            .match_header("user-agent", "some-app/1.0")
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let req = api.build_request("some_endpoint", &[]);
+        let res = api.send_request(req).await;
+
+        mock.assert_async().await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn builder_timeout() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .timeout(Duration::from_millis(50))
+            .connect_timeout(Duration::from_secs(1))
+            .build()
+            .unwrap();
+
+        let _mock = server
+            .mock("GET", "/some_endpoint")
+            .with_status(200)
+            .with_body_from_request(|_| {
+                std::thread::sleep(Duration::from_millis(500));
+                "hello there".into()
+            })
+            .create_async()
+            .await;
+
+        let req = api.build_request("some_endpoint", &[]);
+        let res = api.send_request(req).await;
+
+        match res {
+            Err(Error::HttpError(e)) => assert!(e.is_timeout()),
+            _ => panic!("timeout error expected"),
+        }
+    }
+
     #[tokio::test(start_paused = true)]
-    async fn send_request_202_retries() {
+    async fn send_request_rate_limited() {
         let mut server = mockito::Server::new_async().await;
-        let api = BoardGameGeekApi {
-            base_url: server.url(),
-            client: reqwest::Client::new(),
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .rate_limiter(RateLimiter::new(Duration::from_secs(5)))
+            .build()
+            .unwrap();
+        let api_clone = api.clone();
+
+        let mock = server
+            .mock("GET", "/hot")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/hot_list/hot_list.xml")
+                    .expect("failed to load test data"),
+            )
+            .expect(3)
+            .create_async()
+            .await;
+
+        let start = tokio::time::Instant::now();
+        let (hot_list, hot_list_clone) = (api.hot_list(), api_clone.hot_list());
+        let (first, second) = tokio::join!(hot_list.get(), hot_list_clone.get());
+        let third = api.hot_list().get().await;
+        mock.assert_async().await;
+
+        assert!(first.is_ok() && second.is_ok() && third.is_ok());
+        assert!(start.elapsed() >= Duration::from_secs(10));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn send_request_429_retry_after() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let throttled_mock = server
+            .mock("GET", "/some_endpoint")
+            .with_status(429)
+            .with_header("retry-after", "3")
+            .expect(1)
+            .create_async()
+            .await;
+        let mock = server
+            .mock("GET", "/some_endpoint")
+            .with_status(200)
+            .with_body("hello there")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let start = tokio::time::Instant::now();
+        let req = api.build_request("some_endpoint", &[]);
+        let res = api.send_request(req).await;
+
+        throttled_mock.assert_async().await;
+        mock.assert_async().await;
+        assert!(start.elapsed() >= Duration::from_secs(3));
+        assert!(res.unwrap().text().await.unwrap() == "hello there");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn send_request_503_max_attempts() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .retry_policy(
+                RetryPolicy::new()
+                    .max_attempts(3)
+                    .base_delay(Duration::from_secs(1)),
+            )
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/some_endpoint")
+            .with_status(503)
+            .expect(3)
+            .create_async()
+            .await;
+
+        let start = tokio::time::Instant::now();
+        let req = api.build_request("some_endpoint", &[]);
+        let res = api.send_request(req).await;
+
+        mock.assert_async().await;
+        assert!(start.elapsed() >= Duration::from_secs(3));
+        match res {
+            Err(e @ Error::HttpError(_)) => assert!(e.is_retryable()),
+            _ => panic!("http error expected"),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn send_site_request_not_retried() {
+        let mut server = mockito::Server::new_async().await;
+        let api = crate::session::logged_in_api(&server);
+
+        let mock = server
+            .mock("POST", "/geekplay.php")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let req = api.build_site_request("geekplay.php").unwrap();
+        let res = api.send_request(req).await;
+
+        mock.assert_async().await;
+        assert!(matches!(res, Err(Error::HttpError(_))));
+    }
+
+    #[tokio::test]
+    async fn send_request_never_retry() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .retry_policy(RetryPolicy::never())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/some_endpoint")
+            .with_status(202)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let req = api.build_request("some_endpoint", &[]);
+        let res = api.send_request(req).await;
+
+        mock.assert_async().await;
+        assert!(matches!(res, Err(Error::MaxRetryError(0))));
+    }
+
+    #[tokio::test]
+    async fn send_request_202_not_in_retry_statuses() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .retry_policy(
+                RetryPolicy::new().retry_statuses([reqwest::StatusCode::SERVICE_UNAVAILABLE]),
+            )
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/some_endpoint")
+            .with_status(202)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let req = api.build_request("some_endpoint", &[]);
+        let res = api.send_request(req).await;
+
+        mock.assert_async().await;
+        assert!(matches!(res, Err(Error::MaxRetryError(0))));
+    }
+
+    #[tokio::test]
+    async fn execute_request_cached() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .cache(ResponseCache::new(10, Duration::from_secs(3600)))
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/hot")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/hot_list/hot_list.xml")
+                    .expect("failed to load test data"),
+            )
+            .expect(2)
+            .create_async()
+            .await;
+
+        let first = api.hot_list().get().await;
+        let second = api.hot_list().get().await;
+        assert!(first.is_ok() && second.is_ok());
+        assert_eq!(first.unwrap(), second.unwrap());
+        assert_eq!(api.cache().unwrap().len(), 1);
+
+        // A different query is cached separately.
+        let by_type = api.hot_list().get_by_type(crate::HotListType::Rpg).await;
+        assert!(by_type.is_ok());
+        assert_eq!(api.cache().unwrap().len(), 2);
+
+        api.cache().unwrap().invalidate_endpoint("hot");
+        assert!(api.cache().unwrap().is_empty());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn execute_request_cached_by_session() {
+        let mut server = mockito::Server::new_async().await;
+        let logged_in = BoardGameGeekApi {
+            cache: Some(Arc::new(ResponseCache::new(10, Duration::from_secs(3600)))),
+            ..crate::session::logged_in_api(&server)
         };
+        let logged_out = BoardGameGeekApi {
+            session: None,
+            ..logged_in.clone()
+        };
+
+        let private_mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::Any)
+            .match_header("cookie", "SessionID=xyz789")
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/collection/collection_owned_private.xml")
+                    .expect("failed to load test data"),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let public_mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::Any)
+            .match_header("cookie", Matcher::Missing)
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/collection/collection_owned_single.xml")
+                    .expect("failed to load test data"),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let query_params = || crate::CollectionQueryParams::new().show_private(true);
+        let private = logged_in
+            .collection()
+            .get_from_query("somename", query_params())
+            .await
+            .expect("failed to get collection");
+        assert!(private.items[0].private_info.is_some());
+
+        // The clone that isn't logged in mustn't get the private response from the cache.
+        let public = logged_out
+            .collection()
+            .get_from_query("somename", query_params())
+            .await
+            .expect("failed to get collection");
+        assert!(public.items[0].private_info.is_none());
+
+        // Each is then served its own response from the cache.
+        let private_cached = logged_in
+            .collection()
+            .get_from_query("somename", query_params())
+            .await
+            .expect("failed to get collection");
+        let public_cached = logged_out
+            .collection()
+            .get_from_query("somename", query_params())
+            .await
+            .expect("failed to get collection");
+        private_mock.assert_async().await;
+        public_mock.assert_async().await;
+        assert_eq!(private_cached, private);
+        assert_eq!(public_cached, public);
+        assert_eq!(logged_in.cache().unwrap().len(), 2);
+
+        // Logging out of one clone leaves the responses cached for the other.
+        let mut logged_in_clone = logged_in.clone();
+        logged_in_clone.logout();
+        assert_eq!(logged_in.cache().unwrap().len(), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn send_request_202_retries() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .site_base_url(server.url())
+            .build()
+            .unwrap();
 
         let mock = server
             .mock("GET", "/some_endpoint")
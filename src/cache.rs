@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+/// An in-memory cache of responses from the API, so that repeated requests for the
+/// same data skip the network.
+///
+/// Responses are cached by the endpoint and query parameters of the request, along with
+/// the username of the logged in user if any, so that private data returned to a logged
+/// in user is never returned to another user or to a clone of the API that is not
+/// logged in. Responses are kept for a time to live, which can be set separately for
+/// each endpoint. Once the cache holds the maximum number of responses, the least
+/// recently used is evicted to make room for new ones.
+///
+/// Set on the API with [crate::BoardGameGeekApiBuilder::cache], the cache is then shared
+/// by all clones of the API.
+#[derive(Debug)]
+pub struct ResponseCache {
+    // The most responses to hold at once.
+    max_entries: usize,
+    // How long to keep responses from endpoints without their own time to live.
+    default_ttl: Duration,
+    // How long to keep responses from each endpoint, by the name of the endpoint.
+    endpoint_ttls: HashMap<String, Duration>,
+    entries: Mutex<CacheEntries>,
+}
+
+#[derive(Debug, Default)]
+struct CacheEntries {
+    entries: HashMap<CacheKey, CacheEntry>,
+    // Incremented on every use of an entry, to track which was least recently used.
+    uses: u64,
+}
+
+// Responses are cached by the username of the logged in user, if any, and the URL of
+// the request including the query parameters.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct CacheKey {
+    pub(crate) username: Option<String>,
+    pub(crate) url: String,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    endpoint: String,
+    body: String,
+    expires_at: Instant,
+    last_used: u64,
+}
+
+impl ResponseCache {
+    /// Constructs a cache holding up to max_entries responses, each kept for the
+    /// default time to live unless one is set for its endpoint.
+    pub fn new(max_entries: usize, default_ttl: Duration) -> Self {
+        Self {
+            max_entries,
+            default_ttl,
+            endpoint_ttls: HashMap::new(),
+            entries: Mutex::new(CacheEntries::default()),
+        }
+    }
+
+    /// Sets how long to keep responses from an endpoint, by the name of the endpoint
+    /// in the API such as "hot", "thing" or "search". A time to live of zero means
+    /// responses from the endpoint are not cached.
+    pub fn endpoint_ttl(mut self, endpoint: impl Into<String>, ttl: Duration) -> Self {
+        self.endpoint_ttls.insert(endpoint.into(), ttl);
+        self
+    }
+
+    /// Removes all cached responses from an endpoint, such as after changing a
+    /// user's collection.
+    pub fn invalidate_endpoint(&self, endpoint: &str) {
+        self.lock()
+            .entries
+            .retain(|_, entry| entry.endpoint != endpoint);
+    }
+
+    /// Removes all cached responses.
+    pub fn clear(&self) {
+        self.lock().entries.clear();
+    }
+
+    /// The number of responses currently cached, including any that have expired but
+    /// not yet been removed.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Whether there are no responses cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Gets the cached response for a request, if it hasn't expired.
+    pub(crate) fn get(&self, key: &CacheKey) -> Option<String> {
+        let mut entries = self.lock();
+        entries.uses += 1;
+        let uses = entries.uses;
+        match entries.entries.get_mut(key) {
+            Some(entry) if entry.expires_at > Instant::now() => {
+                entry.last_used = uses;
+                Some(entry.body.clone())
+            }
+            Some(_) => {
+                entries.entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    // Caches the response for a request to an endpoint, evicting the least recently used
+    // response if the cache is full.
+    pub(crate) fn insert(&self, endpoint: &str, key: CacheKey, body: String) {
+        let ttl = self
+            .endpoint_ttls
+            .get(endpoint)
+            .copied()
+            .unwrap_or(self.default_ttl);
+        if ttl.is_zero() || self.max_entries == 0 {
+            return;
+        }
+        let mut entries = self.lock();
+        entries.uses += 1;
+        let uses = entries.uses;
+        let now = Instant::now();
+        if !entries.entries.contains_key(&key) && entries.entries.len() >= self.max_entries {
+            // Expired entries are removed first, before evicting any still valid.
+            entries.entries.retain(|_, entry| entry.expires_at > now);
+            if entries.entries.len() >= self.max_entries {
+                let least_recently_used = entries
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.last_used)
+                    .map(|(key, _)| key.clone());
+                if let Some(least_recently_used) = least_recently_used {
+                    entries.entries.remove(&least_recently_used);
+                }
+            }
+        }
+        entries.entries.insert(
+            key,
+            CacheEntry {
+                endpoint: endpoint.to_string(),
+                body,
+                expires_at: now + ttl,
+                last_used: uses,
+            },
+        );
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CacheEntries> {
+        self.entries.lock().expect("response cache lock poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(username: Option<&str>, url: &str) -> CacheKey {
+        CacheKey {
+            username: username.map(str::to_string),
+            url: url.to_string(),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn get_expired() {
+        let cache = ResponseCache::new(10, Duration::from_secs(60))
+            .endpoint_ttl("hot", Duration::from_secs(3600))
+            .endpoint_ttl("plays", Duration::ZERO);
+
+        cache.insert("thing", key(None, "/thing?id=1"), "thing".into());
+        cache.insert("hot", key(None, "/hot"), "hot".into());
+        cache.insert(
+            "plays",
+            key(None, "/plays?username=somename"),
+            "plays".into(),
+        );
+        assert_eq!(cache.get(&key(None, "/thing?id=1")), Some("thing".into()));
+        assert_eq!(cache.get(&key(None, "/hot")), Some("hot".into()));
+        assert_eq!(cache.get(&key(None, "/plays?username=somename")), None);
+
+        tokio::time::sleep(Duration::from_secs(61)).await;
+        assert_eq!(cache.get(&key(None, "/thing?id=1")), None);
+        assert_eq!(cache.get(&key(None, "/hot")), Some("hot".into()));
+
+        tokio::time::sleep(Duration::from_secs(3600)).await;
+        assert_eq!(cache.get(&key(None, "/hot")), None);
+        assert!(cache.is_empty());
+    }
+
+    #[tokio::test]
+    async fn insert_evicts_least_recently_used() {
+        let cache = ResponseCache::new(2, Duration::from_secs(60));
+
+        cache.insert("thing", key(None, "/thing?id=1"), "1".into());
+        cache.insert("thing", key(None, "/thing?id=2"), "2".into());
+        // Use the first so that the second is the least recently used.
+        assert_eq!(cache.get(&key(None, "/thing?id=1")), Some("1".into()));
+        cache.insert("thing", key(None, "/thing?id=3"), "3".into());
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&key(None, "/thing?id=1")), Some("1".into()));
+        assert_eq!(cache.get(&key(None, "/thing?id=2")), None);
+        assert_eq!(cache.get(&key(None, "/thing?id=3")), Some("3".into()));
+    }
+
+    #[tokio::test]
+    async fn invalidate() {
+        let cache = ResponseCache::new(10, Duration::from_secs(60));
+
+        cache.insert("thing", key(None, "/thing?id=1"), "1".into());
+        cache.insert(
+            "collection",
+            key(None, "/collection?username=somename"),
+            "c".into(),
+        );
+        cache.invalidate_endpoint("collection");
+        assert_eq!(cache.get(&key(None, "/collection?username=somename")), None);
+        assert_eq!(cache.get(&key(None, "/thing?id=1")), Some("1".into()));
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[tokio::test]
+    async fn get_by_username() {
+        let cache = ResponseCache::new(10, Duration::from_secs(60));
+
+        cache.insert(
+            "collection",
+            key(
+                Some("somename"),
+                "/collection?username=somename&showprivate=1",
+            ),
+            "private".into(),
+        );
+        assert_eq!(
+            cache.get(&key(
+                Some("somename"),
+                "/collection?username=somename&showprivate=1"
+            )),
+            Some("private".into()),
+        );
+        assert_eq!(
+            cache.get(&key(None, "/collection?username=somename&showprivate=1")),
+            None,
+        );
+        assert_eq!(
+            cache.get(&key(
+                Some("othername"),
+                "/collection?username=somename&showprivate=1"
+            )),
+            None,
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use core::fmt;
+use std::collections::HashMap;
 use std::ops::RangeInclusive;
 
 use chrono::{DateTime, Duration, NaiveDate, Utc};
@@ -7,14 +9,16 @@ use serde::Deserialize;
 use crate::api::BoardGameGeekApi;
 use crate::utils::{
     date_deserializer, deserialize_1_0_bool, deserialize_game_ratings,
-    deserialize_game_ratings_brief, deserialize_minutes, deserialize_rank_value_enum,
-    deserialize_wishlist_priority,
+    deserialize_game_ratings_brief, deserialize_minutes, deserialize_optional_string,
+    deserialize_optional_u64, deserialize_rank_value_enum, deserialize_wishlist_priority,
 };
-use crate::Result;
+use crate::{PlayerCountPoll, Price, Result, ThingQueryParams, Version};
 
 pub trait CollectionItemType<'a>: DeserializeOwned {
     fn base_query(username: &'a str) -> BaseCollectionQuery<'a>;
 
+    fn get_id(&self) -> u64;
+
     fn get_stats(&self) -> Option<CollectionItemStatsBrief>;
 }
 
@@ -26,6 +30,10 @@ impl<'a> CollectionItemType<'a> for CollectionItemBrief {
         }
     }
 
+    fn get_id(&self) -> u64 {
+        self.id
+    }
+
     fn get_stats(&self) -> Option<CollectionItemStatsBrief> {
         self.stats.clone()
     }
@@ -39,6 +47,10 @@ impl<'a> CollectionItemType<'a> for CollectionItem {
         }
     }
 
+    fn get_id(&self) -> u64 {
+        self.id
+    }
+
     fn get_stats(&self) -> Option<CollectionItemStatsBrief> {
         self.stats.as_ref().map(|stats| CollectionItemStatsBrief {
             min_players: stats.min_players,
@@ -74,15 +86,24 @@ pub struct CollectionItemBrief {
     /// The collection ID of the object.
     #[serde(rename = "collid")]
     pub collection_id: u64,
-    /// The type of game, which will either be boardgame or expansion.
+    /// The type of the item, such as a board game, expansion, or role playing game
+    /// item. See [ThingType] for why expansions can be returned as board games.
     #[serde(rename = "subtype")]
-    pub item_type: GameType,
+    pub item_type: ThingType,
     /// The name of the game.
     pub name: String,
     /// Status of the game in this collection, such as own, preowned, wishlist.
     pub status: CollectionItemStatus,
     /// Game stats such as number of players, can sometimes be omitted from the result.
     pub stats: Option<CollectionItemStatsBrief>,
+    /// Private information about the user's copy of the game. Only included when
+    /// requesting private information for a user that is logged in.
+    #[serde(default, rename = "privateinfo")]
+    pub private_info: Option<CollectionItemPrivateInfo>,
+    /// The version of the game that the user owns, such as a particular edition or
+    /// translation. Only included when requesting versions, and the user has set one.
+    #[serde(default, deserialize_with = "deserialize_collection_version")]
+    pub version: Option<Version>,
 }
 
 /// A game or game expansion in a collection.
@@ -94,48 +115,213 @@ pub struct CollectionItem {
     /// The collection ID of the object.
     #[serde(rename = "collid")]
     pub collection_id: u64,
-    /// The type of game, which will either be boardgame or expansion.
+    /// The type of the item, such as a board game, expansion, or role playing game
+    /// item. See [ThingType] for why expansions can be returned as board games.
     #[serde(rename = "subtype")]
-    pub item_type: GameType,
+    pub item_type: ThingType,
     /// The name of the game.
     pub name: String,
-    /// The year the game was first published.
-    #[serde(rename = "yearpublished")]
-    pub year_published: i64,
-    /// A link to a jpg image for the game.
-    pub image: String,
-    /// A link to a jpg thumbnail image for the game.
-    pub thumbnail: String,
+    /// The year the game was first published. Omitted if the item has no year of
+    /// publication, which is common for role playing game items and video games.
+    #[serde(default, rename = "yearpublished")]
+    pub year_published: Option<i64>,
+    /// A link to a jpg image for the game. Omitted if the item has no image.
+    #[serde(default)]
+    pub image: Option<String>,
+    /// A link to a jpg thumbnail image for the game. Omitted if the item has no image.
+    #[serde(default)]
+    pub thumbnail: Option<String>,
     /// Status of the game in this collection, such as own, preowned, wishlist.
     pub status: CollectionItemStatus,
     /// The number of times the user has played the game.
     #[serde(rename = "numplays")]
     pub number_of_plays: u64,
+    /// The user's comment on the game.
+    #[serde(default)]
+    pub comment: Option<String>,
+    /// A description of the condition of the user's copy of the game.
+    #[serde(default, rename = "conditiontext")]
+    pub condition_text: Option<String>,
+    /// The parts of the game that the user is missing and wants.
+    #[serde(default, rename = "wantpartslist")]
+    pub want_parts_list: Option<String>,
+    /// The spare parts of the game that the user has.
+    #[serde(default, rename = "haspartslist")]
+    pub has_parts_list: Option<String>,
+    /// The user's comment on why the game is on their wishlist.
+    #[serde(default, rename = "wishlistcomment")]
+    pub wishlist_comment: Option<String>,
     /// Game stats such as number of players, can sometimes be omitted from the result.
     pub stats: Option<CollectionItemStats>,
+    /// Private information about the user's copy of the game. Only included when
+    /// requesting private information for a user that is logged in.
+    #[serde(default, rename = "privateinfo")]
+    pub private_info: Option<CollectionItemPrivateInfo>,
+    /// The version of the game that the user owns, such as a particular edition or
+    /// translation. Only included when requesting versions, and the user has set one.
+    #[serde(default, deserialize_with = "deserialize_collection_version")]
+    pub version: Option<Version>,
 }
 
-/// The type of game, board game or expansion.
+// Intermediary struct needed due to the way the XML is structured, with the version
+// nested in an item tag inside the version tag.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
-pub enum GameType {
+struct CollectionVersion {
+    item: Version,
+}
+
+fn deserialize_collection_version<'de, D>(
+    deserializer: D,
+) -> core::result::Result<Option<Version>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let version: CollectionVersion = Deserialize::deserialize(deserializer)?;
+    Ok(Some(version.item))
+}
+
+/// The type of an item on the site, such as a board game, role playing game, or
+/// a person or company involved in making them.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub enum ThingType {
     /// A board game, or expansion.
     ///
     /// Due to the way the API works, this type can include expansions too.
     /// If a request is made for just board games, or the game type is not
-    /// filtered, then both items with a type of [GameType::BoardGame] and
-    /// those with a type of [GameType::BoardGameExpansion] will be returned,
-    /// and they will ALL have the type of [GameType::BoardGame]. However when
+    /// filtered, then both items with a type of [ThingType::BoardGame] and
+    /// those with a type of [ThingType::BoardGameExpansion] will be returned,
+    /// and they will ALL have the type of [ThingType::BoardGame]. However when
     /// requesting just expansions, the returned items will correctly have the
-    /// type [GameType::BoardGameExpansion].
+    /// type [ThingType::BoardGameExpansion].
     ///
     /// A workaround to this can be to make 2 requests, one to include
-    /// [GameType::BoardGame] and exclude [GameType::BoardGameExpansion],
-    /// followed by another to just include [GameType::BoardGameExpansion].
+    /// [ThingType::BoardGame] and exclude [ThingType::BoardGameExpansion],
+    /// followed by another to just include [ThingType::BoardGameExpansion].
     #[serde(rename = "boardgame")]
     BoardGame,
     /// A board game expansion.
     #[serde(rename = "boardgameexpansion")]
     BoardGameExpansion,
+    /// A board game accessory, such as upgraded components or a playmat.
+    #[serde(rename = "boardgameaccessory")]
+    BoardGameAccessory,
+    /// A board game designer.
+    #[serde(rename = "boardgamedesigner")]
+    BoardGameDesigner,
+    /// A board game artist.
+    #[serde(rename = "boardgameartist")]
+    BoardGameArtist,
+    /// A board game publisher.
+    #[serde(rename = "boardgamepublisher")]
+    BoardGamePublisher,
+    /// Any person involved in board games, such as a designer or artist.
+    #[serde(rename = "boardgameperson")]
+    BoardGamePerson,
+    /// Any company involved in board games, such as a publisher.
+    #[serde(rename = "boardgamecompany")]
+    BoardGameCompany,
+    /// A family of related board games, such as a series or shared theme.
+    #[serde(rename = "boardgamefamily")]
+    BoardGameFamily,
+    /// A role playing game.
+    #[serde(rename = "rpg")]
+    Rpg,
+    /// An item for a role playing game, such as a rulebook or adventure.
+    #[serde(rename = "rpgitem")]
+    RpgItem,
+    /// An issue of a role playing game periodical.
+    #[serde(rename = "rpgissue")]
+    RpgIssue,
+    /// A role playing game periodical, such as a magazine.
+    #[serde(rename = "rpgperiodical")]
+    RpgPeriodical,
+    /// A family of related role playing game items.
+    #[serde(rename = "rpgfamily")]
+    RpgFamily,
+    /// Any person involved in role playing games.
+    #[serde(rename = "rpgperson")]
+    RpgPerson,
+    /// Any company involved in role playing games.
+    #[serde(rename = "rpgcompany")]
+    RpgCompany,
+    /// A video game.
+    #[serde(rename = "videogame")]
+    VideoGame,
+    /// Any company involved in video games.
+    #[serde(rename = "videogamecompany")]
+    VideoGameCompany,
+    /// A piece of video game hardware, such as a console.
+    #[serde(rename = "videogamehardware")]
+    VideoGameHardware,
+    /// A platform video games are released on.
+    #[serde(rename = "videogameplatform")]
+    VideoGamePlatform,
+    /// A video game franchise.
+    #[serde(rename = "videogamefranchise")]
+    VideoGameFranchise,
+    /// A series of video games.
+    #[serde(rename = "videogameseries")]
+    VideoGameSeries,
+    /// A character appearing in video games.
+    #[serde(rename = "videogamecharacter")]
+    VideoGameCharacter,
+    /// A video game developer.
+    #[serde(rename = "videogamedeveloper")]
+    VideoGameDeveloper,
+    /// A video game publisher.
+    #[serde(rename = "videogamepublisher")]
+    VideoGamePublisher,
+    /// A video game genre.
+    #[serde(rename = "videogamegenre")]
+    VideoGameGenre,
+    /// A video game theme.
+    #[serde(rename = "videogametheme")]
+    VideoGameTheme,
+    /// Any other type of item not listed here. This is only returned by the API,
+    /// requests made with it will be rejected.
+    #[serde(other)]
+    Other,
+}
+
+/// The previous name for [ThingType], from when it only included board games and
+/// expansions.
+pub type GameType = ThingType;
+
+impl fmt::Display for ThingType {
+    // Formats the type as the value the API uses for it in query parameters.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            ThingType::BoardGame => "boardgame",
+            ThingType::BoardGameExpansion => "boardgameexpansion",
+            ThingType::BoardGameAccessory => "boardgameaccessory",
+            ThingType::BoardGameDesigner => "boardgamedesigner",
+            ThingType::BoardGameArtist => "boardgameartist",
+            ThingType::BoardGamePublisher => "boardgamepublisher",
+            ThingType::BoardGamePerson => "boardgameperson",
+            ThingType::BoardGameCompany => "boardgamecompany",
+            ThingType::BoardGameFamily => "boardgamefamily",
+            ThingType::Rpg => "rpg",
+            ThingType::RpgItem => "rpgitem",
+            ThingType::RpgIssue => "rpgissue",
+            ThingType::RpgPeriodical => "rpgperiodical",
+            ThingType::RpgFamily => "rpgfamily",
+            ThingType::RpgPerson => "rpgperson",
+            ThingType::RpgCompany => "rpgcompany",
+            ThingType::VideoGame => "videogame",
+            ThingType::VideoGameCompany => "videogamecompany",
+            ThingType::VideoGameHardware => "videogamehardware",
+            ThingType::VideoGamePlatform => "videogameplatform",
+            ThingType::VideoGameFranchise => "videogamefranchise",
+            ThingType::VideoGameSeries => "videogameseries",
+            ThingType::VideoGameCharacter => "videogamecharacter",
+            ThingType::VideoGameDeveloper => "videogamedeveloper",
+            ThingType::VideoGamePublisher => "videogamepublisher",
+            ThingType::VideoGameGenre => "videogamegenre",
+            ThingType::VideoGameTheme => "videogametheme",
+            ThingType::Other => "other",
+        };
+        write!(f, "{value}")
+    }
 }
 
 /// The status of the game in the user's collection, such as preowned or wishlist.
@@ -178,6 +364,95 @@ pub struct CollectionItemStatus {
     pub last_modified: DateTime<Utc>,
 }
 
+/// Private information that a user has recorded about their copy of a game, such
+/// as how much they paid for it. Any of the fields can be omitted if not filled in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CollectionItemPrivateInfo {
+    /// The price the user paid for the game.
+    pub price_paid: Option<Price>,
+    /// The current value of the game.
+    pub current_value: Option<Price>,
+    /// The number of copies of the game the user has.
+    pub quantity: Option<u64>,
+    /// The date the user acquired the game.
+    pub acquisition_date: Option<NaiveDate>,
+    /// Where the user acquired the game from, such as a shop or another user.
+    pub acquired_from: Option<String>,
+    /// Where the game is kept.
+    pub inventory_location: Option<String>,
+    /// A comment only visible to the user.
+    pub private_comment: Option<String>,
+}
+
+// Intermediary struct needed due to the way the XML is structured. All the values
+// are returned as empty strings when not filled in, and prices are split over two
+// attributes.
+#[derive(Debug, Deserialize)]
+struct XmlPrivateInfo {
+    #[serde(default, rename = "pp_currency")]
+    price_paid_currency: String,
+    #[serde(default, rename = "pricepaid")]
+    price_paid: String,
+    #[serde(default, rename = "cv_currency")]
+    current_value_currency: String,
+    #[serde(default, rename = "currvalue")]
+    current_value: String,
+    #[serde(default, deserialize_with = "deserialize_optional_u64")]
+    quantity: Option<u64>,
+    #[serde(default, rename = "acquisitiondate")]
+    acquisition_date: String,
+    #[serde(
+        default,
+        rename = "acquiredfrom",
+        deserialize_with = "deserialize_optional_string"
+    )]
+    acquired_from: Option<String>,
+    #[serde(
+        default,
+        rename = "inventorylocation",
+        deserialize_with = "deserialize_optional_string"
+    )]
+    inventory_location: Option<String>,
+    #[serde(default, rename = "privatecomment")]
+    private_comment: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for CollectionItemPrivateInfo {
+    fn deserialize<D: serde::de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        let private_info = XmlPrivateInfo::deserialize(deserializer)?;
+        // Prices are entered freely by users, so any that can't be parsed are treated as
+        // not filled in rather than failing the whole collection.
+        let price = |amount: String, currency: String| match amount.trim().is_empty() {
+            true => None,
+            false => Price::new_rounded(&amount, currency),
+        };
+        // Dates that have not been filled in can be returned as all zeroes.
+        let acquisition_date = match private_info.acquisition_date.as_str() {
+            "" | "0000-00-00" => None,
+            date => Some(
+                NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(serde::de::Error::custom)?,
+            ),
+        };
+
+        Ok(Self {
+            price_paid: price(private_info.price_paid, private_info.price_paid_currency),
+            current_value: price(
+                private_info.current_value,
+                private_info.current_value_currency,
+            ),
+            quantity: private_info.quantity,
+            acquisition_date,
+            acquired_from: private_info.acquired_from,
+            inventory_location: private_info.inventory_location,
+            private_comment: private_info
+                .private_comment
+                .filter(|comment| !comment.is_empty()),
+        })
+    }
+}
+
 /// The status of the game in the user's collection, such as preowned or wishlist.
 /// Can be any or none of them.
 #[derive(Copy, Clone, Debug, Deserialize, PartialEq, PartialOrd)]
@@ -194,6 +469,19 @@ pub enum WishlistPriority {
     MustHave,
 }
 
+impl WishlistPriority {
+    // The value the API uses for the priority, from 1 for the highest to 5 for the lowest.
+    pub(crate) fn api_value(self) -> &'static str {
+        match self {
+            WishlistPriority::DontBuyThis => "5",
+            WishlistPriority::ThinkingAboutIt => "4",
+            WishlistPriority::LikeToHave => "3",
+            WishlistPriority::LoveToHave => "2",
+            WishlistPriority::MustHave => "1",
+        }
+    }
+}
+
 /// Stats of the game such as playercount and duration. Can be omitted from the response.
 /// More stats can be found from the specific game endpoint.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
@@ -335,13 +623,13 @@ pub struct BaseCollectionQuery<'q> {
 pub struct CollectionQueryParams {
     /// Include only results for this item type.
     ///
-    /// Note, if this is set to [GameType::BoardGame] then it will include both
+    /// Note, if this is set to [ThingType::BoardGame] then it will include both
     /// board games and expansions, but set the type of all of them to be
-    /// [GameType::BoardGame] in the results. Explicitly exclude [GameType::BoardGameExpansion]
+    /// [ThingType::BoardGame] in the results. Explicitly exclude [ThingType::BoardGameExpansion]
     /// to avoid this.
-    item_type: Option<GameType>,
+    item_type: Option<ThingType>,
     /// Exclude results for this item type.
-    exclude_item_type: Option<GameType>,
+    exclude_item_type: Option<ThingType>,
     /// Include items the user owns if true, exclude if false.
     include_owned: Option<bool>,
     /// Include items the user previously owned if true, exclude if false.
@@ -390,6 +678,8 @@ pub struct CollectionQueryParams {
     show_private: Option<bool>,
     /// ID of a particular item in a collection.
     collection_id: Option<u64>,
+    /// Include the version of the game that the user owns.
+    include_version: Option<bool>,
 }
 
 impl CollectionQueryParams {
@@ -399,14 +689,14 @@ impl CollectionQueryParams {
     }
 
     /// Sets the item_type field, so that only that type of item will be returned.
-    pub fn item_type(mut self, item_type: GameType) -> Self {
+    pub fn item_type(mut self, item_type: ThingType) -> Self {
         self.item_type = Some(item_type);
         self
     }
 
     /// Set the exclude_item_type field, so that that type of item will be excluded from.
     /// the results.
-    pub fn exclude_item_type(mut self, exclude_item_type: GameType) -> Self {
+    pub fn exclude_item_type(mut self, exclude_item_type: ThingType) -> Self {
         self.exclude_item_type = Some(exclude_item_type);
         self
     }
@@ -585,6 +875,14 @@ impl CollectionQueryParams {
         self.collection_id = Some(collection_id);
         self
     }
+
+    /// Sets the include_version field. If set then the version of each game
+    /// that the user owns, such as the edition, publisher and language, will be
+    /// included in the results.
+    pub fn include_version(mut self, include_version: bool) -> Self {
+        self.include_version = Some(include_version);
+        self
+    }
 }
 
 /// Struct for building a query for the request to the collection endpoint.
@@ -610,21 +908,11 @@ impl<'a> CollectionQueryBuilder<'a> {
             true => query_params.push(("brief", "1".to_string())),
             false => query_params.push(("brief", "0".to_string())),
         }
-        match self.params.item_type {
-            Some(GameType::BoardGame) => query_params.push(("subtype", "boardgame".to_string())),
-            Some(GameType::BoardGameExpansion) => {
-                query_params.push(("subtype", "boardgameexpansion".to_string()))
-            }
-            None => {}
+        if let Some(item_type) = self.params.item_type {
+            query_params.push(("subtype", item_type.to_string()));
         }
-        match self.params.exclude_item_type {
-            Some(GameType::BoardGame) => {
-                query_params.push(("excludesubtype", "boardgame".to_string()))
-            }
-            Some(GameType::BoardGameExpansion) => {
-                query_params.push(("excludesubtype", "boardgameexpansion".to_string()))
-            }
-            None => {}
+        if let Some(exclude_item_type) = self.params.exclude_item_type {
+            query_params.push(("excludesubtype", exclude_item_type.to_string()));
         }
         match self.params.include_owned {
             Some(true) => query_params.push(("own", "1".to_string())),
@@ -666,23 +954,11 @@ impl<'a> CollectionQueryBuilder<'a> {
             Some(false) => query_params.push(("wishlist", "0".to_string())),
             None => {}
         }
-        match self.params.wishlist_priority {
-            Some(WishlistPriority::DontBuyThis) => {
-                query_params.push(("wishlistpriority", "5".to_string()))
-            }
-            Some(WishlistPriority::ThinkingAboutIt) => {
-                query_params.push(("wishlistpriority", "4".to_string()))
-            }
-            Some(WishlistPriority::LikeToHave) => {
-                query_params.push(("wishlistpriority", "3".to_string()))
-            }
-            Some(WishlistPriority::LoveToHave) => {
-                query_params.push(("wishlistpriority", "2".to_string()))
-            }
-            Some(WishlistPriority::MustHave) => {
-                query_params.push(("wishlistpriority", "1".to_string()))
-            }
-            None => {}
+        if let Some(wishlist_priority) = self.params.wishlist_priority {
+            query_params.push((
+                "wishlistpriority",
+                wishlist_priority.api_value().to_string(),
+            ));
         }
         if let Some(modified_since) = self.params.modified_since {
             query_params.push((
@@ -748,6 +1024,11 @@ impl<'a> CollectionQueryBuilder<'a> {
         if let Some(collection_id) = self.params.collection_id {
             query_params.push(("collid", collection_id.to_string()));
         }
+        match self.params.include_version {
+            Some(true) => query_params.push(("version", "1".to_string())),
+            Some(false) => query_params.push(("version", "0".to_string())),
+            None => {}
+        }
         query_params
     }
 }
@@ -828,6 +1109,68 @@ impl<'api, T: CollectionItemType<'api> + 'api> CollectionApi<'api, T> {
         Ok(collection)
     }
 
+    /// Gets all the games that the community has voted best at the given player count,
+    /// according to the suggested player count poll for each game.
+    ///
+    /// The poll is not included in the collection, so an extra request is made to the
+    /// thing endpoint for the games in the collection, see [crate::ThingApi::get_many].
+    /// Games without a player count poll are excluded.
+    pub async fn get_by_best_player_count(
+        &self,
+        username: &'api str,
+        player_count: u32,
+        query_params: CollectionQueryParams,
+    ) -> Result<Collection<T>> {
+        self.get_by_player_count_poll(username, query_params, |poll| poll.is_best_at(player_count))
+            .await
+    }
+
+    /// Gets all the games that the community has voted best or recommended at the given
+    /// player count, according to the suggested player count poll for each game.
+    ///
+    /// The poll is not included in the collection, so an extra request is made to the
+    /// thing endpoint for the games in the collection, see [crate::ThingApi::get_many].
+    /// Games without a player count poll are excluded.
+    pub async fn get_by_recommended_player_count(
+        &self,
+        username: &'api str,
+        player_count: u32,
+        query_params: CollectionQueryParams,
+    ) -> Result<Collection<T>> {
+        self.get_by_player_count_poll(username, query_params, |poll| {
+            poll.is_recommended_at(player_count)
+        })
+        .await
+    }
+
+    // Gets the collection, then the player count poll for each item in it, and keeps only
+    // the items where the poll matches the filter.
+    async fn get_by_player_count_poll(
+        &self,
+        username: &'api str,
+        query_params: CollectionQueryParams,
+        filter: impl Fn(&PlayerCountPoll) -> bool,
+    ) -> Result<Collection<T>> {
+        let mut collection = self.get_from_query(username, query_params).await?;
+
+        let ids: Vec<u64> = collection.items.iter().map(T::get_id).collect();
+        let things = self
+            .api
+            .thing()
+            .get_many_from_query(&ids, ThingQueryParams::new())
+            .await?;
+        let polls: HashMap<u64, PlayerCountPoll> = things
+            .items
+            .into_iter()
+            .filter_map(|thing| thing.suggested_player_count.map(|poll| (thing.id, poll)))
+            .collect();
+
+        collection
+            .items
+            .retain(|item| polls.get(&item.get_id()).is_some_and(&filter));
+        Ok(collection)
+    }
+
     /// Makes a request from a [CollectionQueryParams].
     pub async fn get_from_query(
         &self,
@@ -847,6 +1190,7 @@ mod tests {
     use mockito::Matcher;
 
     use super::*;
+    use crate::{Link, LinkType};
 
     #[test]
     fn sort_wishlist_priority() {
@@ -856,10 +1200,10 @@ mod tests {
     #[tokio::test]
     async fn get_owned_brief() {
         let mut server = mockito::Server::new_async().await;
-        let api = BoardGameGeekApi {
-            base_url: server.url(),
-            client: reqwest::Client::new(),
-        };
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
 
         let mock = server
             .mock("GET", "/collection")
@@ -888,7 +1232,7 @@ mod tests {
             CollectionItemBrief {
                 id: 131835,
                 collection_id: 118278872,
-                item_type: GameType::BoardGame,
+                item_type: ThingType::BoardGame,
                 name: "Boss Monster: The Dungeon Building Card Game".to_string(),
                 status: CollectionItemStatus {
                     own: true,
@@ -903,18 +1247,391 @@ mod tests {
                     last_modified: Utc.with_ymd_and_hms(2024, 4, 13, 18, 29, 1).unwrap(),
                 },
                 stats: None,
+                private_info: None,
+                version: None,
             },
             "returned collection game doesn't match expected",
         );
     }
 
+    #[tokio::test]
+    async fn get_rpg_items_brief() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("username".into(), "somename".into()),
+                Matcher::UrlEncoded("subtype".into(), "rpgitem".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/collection/collection_brief_rpg_items.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let collection = api
+            .collection_brief()
+            .get_from_query(
+                "somename",
+                CollectionQueryParams::new().item_type(ThingType::RpgItem),
+            )
+            .await;
+        mock.assert_async().await;
+
+        assert!(collection.is_ok(), "error returned when okay expected");
+        let collection = collection.unwrap();
+
+        assert_eq!(collection.items.len(), 1);
+        assert_eq!(collection.items[0].id, 171604);
+        assert_eq!(collection.items[0].item_type, ThingType::RpgItem);
+    }
+
+    #[tokio::test]
+    async fn get_rpg_items() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("username".into(), "somename".into()),
+                Matcher::UrlEncoded("subtype".into(), "rpgitem".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/collection/collection_rpg_items.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let collection = api
+            .collection()
+            .get_from_query(
+                "somename",
+                CollectionQueryParams::new().item_type(ThingType::RpgItem),
+            )
+            .await;
+        mock.assert_async().await;
+
+        assert!(collection.is_ok(), "error returned when okay expected");
+        let collection = collection.unwrap();
+
+        assert_eq!(collection.items.len(), 2);
+        assert_eq!(collection.items[0].item_type, ThingType::RpgItem);
+        assert_eq!(collection.items[0].year_published, None);
+        assert_eq!(
+            collection.items[0].image,
+            Some("https://domain/img.jpg".to_string()),
+        );
+        assert_eq!(
+            collection.items[0].thumbnail,
+            Some("https://domain/thumbnail.jpg".to_string()),
+        );
+        assert_eq!(collection.items[0].number_of_plays, 3);
+        assert_eq!(collection.items[1].id, 336152);
+        assert_eq!(collection.items[1].year_published, None);
+        assert_eq!(collection.items[1].image, None);
+        assert_eq!(collection.items[1].thumbnail, None);
+    }
+
+    #[tokio::test]
+    async fn get_with_private_info() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("username".into(), "somename".into()),
+                Matcher::UrlEncoded("showprivate".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/collection/collection_owned_private.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let collection = api
+            .collection()
+            .get_from_query("somename", CollectionQueryParams::new().show_private(true))
+            .await;
+        mock.assert_async().await;
+
+        assert!(collection.is_ok(), "error returned when okay expected");
+        let collection = collection.unwrap();
+
+        assert_eq!(collection.items.len(), 1);
+        assert_eq!(
+            collection.items[0].private_info,
+            Some(CollectionItemPrivateInfo {
+                price_paid: Some(Price {
+                    minor_units: 4550,
+                    currency: "GBP".into(),
+                }),
+                current_value: Some(Price {
+                    minor_units: 5000,
+                    currency: "GBP".into(),
+                }),
+                quantity: Some(1),
+                acquisition_date: NaiveDate::from_ymd_opt(2021, 3, 5),
+                acquired_from: Some("Forbidden Planet".into()),
+                inventory_location: Some("Shelf 2".into()),
+                private_comment: Some("Signed by the designers at UKGE.".into()),
+            }),
+        );
+    }
+
+    #[tokio::test]
+    async fn get_with_private_info_odd_prices() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("username".into(), "somename".into()),
+                Matcher::UrlEncoded("showprivate".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string(
+                    "test_data/collection/collection_owned_private_odd_prices.xml",
+                )
+                .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let collection = api
+            .collection()
+            .get_from_query("somename", CollectionQueryParams::new().show_private(true))
+            .await;
+        mock.assert_async().await;
+
+        assert!(collection.is_ok(), "error returned when okay expected");
+        let collection = collection.unwrap();
+
+        assert_eq!(collection.items.len(), 1);
+        assert_eq!(
+            collection.items[0].private_info,
+            Some(CollectionItemPrivateInfo {
+                price_paid: Some(Price {
+                    minor_units: 4000,
+                    currency: "USD".into(),
+                }),
+                current_value: None,
+                quantity: Some(1),
+                acquisition_date: None,
+                acquired_from: None,
+                inventory_location: None,
+                private_comment: None,
+            }),
+        );
+    }
+
+    #[tokio::test]
+    async fn get_brief_with_empty_private_info() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("username".into(), "somename".into()),
+                Matcher::UrlEncoded("brief".into(), "1".into()),
+                Matcher::UrlEncoded("showprivate".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/collection/collection_brief_owned_private.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let collection = api
+            .collection_brief()
+            .get_from_query("somename", CollectionQueryParams::new().show_private(true))
+            .await;
+        mock.assert_async().await;
+
+        assert!(collection.is_ok(), "error returned when okay expected");
+        let collection = collection.unwrap();
+
+        assert_eq!(collection.items.len(), 1);
+        assert_eq!(
+            collection.items[0].private_info,
+            Some(CollectionItemPrivateInfo {
+                price_paid: None,
+                current_value: None,
+                quantity: None,
+                acquisition_date: None,
+                acquired_from: None,
+                inventory_location: None,
+                private_comment: None,
+            }),
+        );
+    }
+
+    #[tokio::test]
+    async fn get_with_versions() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("username".into(), "somename".into()),
+                Matcher::UrlEncoded("own".into(), "1".into()),
+                Matcher::UrlEncoded("version".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/collection/collection_owned_versions.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let query = CollectionQueryParams::new()
+            .include_owned(true)
+            .include_version(true);
+        let collection = api.collection().get_from_query("somename", query).await;
+        mock.assert_async().await;
+
+        assert!(collection.is_ok(), "error returned when okay expected");
+        let collection = collection.unwrap();
+
+        assert_eq!(collection.items.len(), 2);
+        assert_eq!(
+            collection.items[0].version,
+            Some(Version {
+                id: 536015,
+                name: "English edition".into(),
+                alternate_names: vec![],
+                image: Some("https://domain/version_img.jpg".into()),
+                thumbnail: Some("https://domain/version_thumb.jpg".into()),
+                year_published: 2020,
+                product_code: Some("CGE00059".into()),
+                width: Some(11.6),
+                length: Some(11.6),
+                depth: Some(2.9),
+                weight: Some(4.85),
+                links: vec![
+                    Link {
+                        link_type: LinkType::Version,
+                        id: 312484,
+                        name: "Lost Ruins of Arnak".into(),
+                        inbound: true,
+                    },
+                    Link {
+                        link_type: LinkType::Publisher,
+                        id: 7345,
+                        name: "Czech Games Edition".into(),
+                        inbound: false,
+                    },
+                    Link {
+                        link_type: LinkType::Language,
+                        id: 2184,
+                        name: "English".into(),
+                        inbound: false,
+                    },
+                ],
+            }),
+        );
+        assert_eq!(collection.items[1].version, None);
+    }
+
+    #[tokio::test]
+    async fn get_commented() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("username".into(), "somename".into()),
+                Matcher::UrlEncoded("comment".into(), "1".into()),
+                Matcher::UrlEncoded("hasparts".into(), "1".into()),
+                Matcher::UrlEncoded("wantparts".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/collection/collection_commented.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let query = CollectionQueryParams::new()
+            .include_commented(true)
+            .has_parts(true)
+            .want_parts(true);
+        let collection = api.collection().get_from_query("somename", query).await;
+        mock.assert_async().await;
+
+        assert!(collection.is_ok(), "error returned when okay expected");
+        let collection = collection.unwrap();
+
+        assert_eq!(collection.items.len(), 1);
+        let item = &collection.items[0];
+        assert_eq!(
+            item.comment,
+            Some("Too long for our group, but a great solo game.".into()),
+        );
+        assert_eq!(
+            item.condition_text,
+            Some("Box corners slightly worn, all components sleeved.".into()),
+        );
+        assert_eq!(
+            item.want_parts_list,
+            Some("One replacement sheep tile.".into()),
+        );
+        assert_eq!(
+            item.has_parts_list,
+            Some("Spare food tiles from a second copy.".into()),
+        );
+        assert_eq!(
+            item.wishlist_comment,
+            Some("Want the Norwegians expansion too.".into()),
+        );
+    }
+
     #[tokio::test]
     async fn get_owned_all() {
         let mut server = mockito::Server::new_async().await;
-        let api = BoardGameGeekApi {
-            base_url: server.url(),
-            client: reqwest::Client::new(),
-        };
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
 
         let mock = server
             .mock("GET", "/collection")
@@ -944,10 +1661,10 @@ mod tests {
     #[tokio::test]
     async fn get_owned() {
         let mut server = mockito::Server::new_async().await;
-        let api = BoardGameGeekApi {
-            base_url: server.url(),
-            client: reqwest::Client::new(),
-        };
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
 
         let mock = server
             .mock("GET", "/collection")
@@ -977,11 +1694,11 @@ mod tests {
             CollectionItem {
                 id: 131835,
                 collection_id: 118278872,
-                item_type: GameType::BoardGame,
+                item_type: ThingType::BoardGame,
                 name: "Boss Monster: The Dungeon Building Card Game".to_string(),
-                year_published: 2013,
-                image: "https://cf.geekdo-images.com/VBwaHyx-NWL3VLcCWKRA0w__original/img/izAmJ81QELl5DoK3y2bzJw55lhA=/0x0/filters:format(jpeg)/pic1732644.jpg".to_string(),
-                thumbnail: "https://cf.geekdo-images.com/VBwaHyx-NWL3VLcCWKRA0w__thumb/img/wisLXxKXbo5-Ci-ZjEj8ryyoN2g=/fit-in/200x150/filters:strip_icc()/pic1732644.jpg".to_string(),
+                year_published: Some(2013),
+                image: Some("https://cf.geekdo-images.com/VBwaHyx-NWL3VLcCWKRA0w__original/img/izAmJ81QELl5DoK3y2bzJw55lhA=/0x0/filters:format(jpeg)/pic1732644.jpg".to_string()),
+                thumbnail: Some("https://cf.geekdo-images.com/VBwaHyx-NWL3VLcCWKRA0w__thumb/img/wisLXxKXbo5-Ci-ZjEj8ryyoN2g=/fit-in/200x150/filters:strip_icc()/pic1732644.jpg".to_string()),
                 status: CollectionItemStatus {
                     own: true,
                     previously_owned: false,
@@ -995,6 +1712,11 @@ mod tests {
                     last_modified: Utc.with_ymd_and_hms(2024, 4, 13, 18, 29, 1).unwrap(),
                 },
                 number_of_plays: 2,
+                comment: None,
+                condition_text: None,
+                want_parts_list: None,
+                has_parts_list: None,
+                wishlist_comment: None,
                 stats: Some(CollectionItemStats {
                     min_players: 2,
                     max_players: 4,
@@ -1029,6 +1751,8 @@ mod tests {
                         ],
                     },
                 }),
+                private_info: None,
+                version: None,
             },
             "returned collection game doesn't match expected",
         );
@@ -1037,10 +1761,10 @@ mod tests {
     #[tokio::test]
     async fn get_wishlist() {
         let mut server = mockito::Server::new_async().await;
-        let api = BoardGameGeekApi {
-            base_url: server.url(),
-            client: reqwest::Client::new(),
-        };
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
 
         let mock = server
             .mock("GET", "/collection")
@@ -1070,11 +1794,11 @@ mod tests {
             CollectionItem {
                 id: 177736,
                 collection_id: 118332974,
-                item_type: GameType::BoardGame,
+                item_type: ThingType::BoardGame,
                 name: "A Feast for Odin".to_string(),
-                year_published: 2016,
-                image: "https://domain/img.jpg".to_string(),
-                thumbnail: "https://domain/thumbnail.jpg".to_string(),
+                year_published: Some(2016),
+                image: Some("https://domain/img.jpg".to_string()),
+                thumbnail: Some("https://domain/thumbnail.jpg".to_string()),
                 status: CollectionItemStatus {
                     own: false,
                     previously_owned: false,
@@ -1088,7 +1812,14 @@ mod tests {
                     last_modified: Utc.with_ymd_and_hms(2024, 4, 18, 19, 28, 17).unwrap(),
                 },
                 number_of_plays: 0,
+                comment: None,
+                condition_text: None,
+                want_parts_list: None,
+                has_parts_list: None,
+                wishlist_comment: None,
                 stats: None,
+                private_info: None,
+                version: None,
             },
             "returned collection game doesn't match expected",
         );
@@ -1097,10 +1828,10 @@ mod tests {
     #[tokio::test]
     async fn get_from_query() {
         let mut server = mockito::Server::new_async().await;
-        let api = BoardGameGeekApi {
-            base_url: server.url(),
-            client: reqwest::Client::new(),
-        };
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
 
         let mock = server
             .mock("GET", "/collection")
@@ -1135,10 +1866,10 @@ mod tests {
     #[tokio::test]
     async fn get_by_player_counts() {
         let mut server = mockito::Server::new_async().await;
-        let api = BoardGameGeekApi {
-            base_url: server.url(),
-            client: reqwest::Client::new(),
-        };
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
 
         let mock = server
             .mock("GET", "/collection")
@@ -1169,11 +1900,11 @@ mod tests {
             CollectionItem {
                 id: 2281,
                 collection_id: 118280658,
-                item_type: GameType::BoardGame,
+                item_type: ThingType::BoardGame,
                 name: "Pictionary".to_string(),
-                year_published: 1985,
-                image: "https://cf.geekdo-images.com/YfUxodD7JSqYitxvjXB69Q__original/img/YRJAlLzkxMuJHVPsdnBLNFpoODA=/0x0/filters:format(png)/pic5147022.png".to_string(),
-                thumbnail: "https://cf.geekdo-images.com/YfUxodD7JSqYitxvjXB69Q__thumb/img/7ls1a8ak5oT7BaKM-rVHpOVrP14=/fit-in/200x150/filters:strip_icc()/pic5147022.png".to_string(),
+                year_published: Some(1985),
+                image: Some("https://cf.geekdo-images.com/YfUxodD7JSqYitxvjXB69Q__original/img/YRJAlLzkxMuJHVPsdnBLNFpoODA=/0x0/filters:format(png)/pic5147022.png".to_string()),
+                thumbnail: Some("https://cf.geekdo-images.com/YfUxodD7JSqYitxvjXB69Q__thumb/img/7ls1a8ak5oT7BaKM-rVHpOVrP14=/fit-in/200x150/filters:strip_icc()/pic5147022.png".to_string()),
                 status: CollectionItemStatus {
                     own: true,
                     previously_owned: false,
@@ -1187,6 +1918,11 @@ mod tests {
                     last_modified: Utc.with_ymd_and_hms(2024, 4, 14, 9, 47, 38).unwrap(),
                 },
                 number_of_plays: 0,
+                comment: None,
+                condition_text: None,
+                want_parts_list: None,
+                has_parts_list: None,
+                wishlist_comment: None,
                 stats: Some(CollectionItemStats {
                     min_players: 3,
                     max_players: 16,
@@ -1221,6 +1957,8 @@ mod tests {
                         ],
                     }
                 }),
+                private_info: None,
+                version: None,
             },
             "returned collection game doesn't match expected",
         );
@@ -1281,10 +2019,10 @@ mod tests {
     #[tokio::test]
     async fn get_by_player_count() {
         let mut server = mockito::Server::new_async().await;
-        let api = BoardGameGeekApi {
-            base_url: server.url(),
-            client: reqwest::Client::new(),
-        };
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
 
         let mock = server
             .mock("GET", "/collection")
@@ -1315,11 +2053,11 @@ mod tests {
             CollectionItem {
                 id: 2281,
                 collection_id: 118280658,
-                item_type: GameType::BoardGame,
+                item_type: ThingType::BoardGame,
                 name: "Pictionary".to_string(),
-                year_published: 1985,
-                image: "https://cf.geekdo-images.com/YfUxodD7JSqYitxvjXB69Q__original/img/YRJAlLzkxMuJHVPsdnBLNFpoODA=/0x0/filters:format(png)/pic5147022.png".to_string(),
-                thumbnail: "https://cf.geekdo-images.com/YfUxodD7JSqYitxvjXB69Q__thumb/img/7ls1a8ak5oT7BaKM-rVHpOVrP14=/fit-in/200x150/filters:strip_icc()/pic5147022.png".to_string(),
+                year_published: Some(1985),
+                image: Some("https://cf.geekdo-images.com/YfUxodD7JSqYitxvjXB69Q__original/img/YRJAlLzkxMuJHVPsdnBLNFpoODA=/0x0/filters:format(png)/pic5147022.png".to_string()),
+                thumbnail: Some("https://cf.geekdo-images.com/YfUxodD7JSqYitxvjXB69Q__thumb/img/7ls1a8ak5oT7BaKM-rVHpOVrP14=/fit-in/200x150/filters:strip_icc()/pic5147022.png".to_string()),
                 status: CollectionItemStatus {
                     own: true,
                     previously_owned: false,
@@ -1333,6 +2071,11 @@ mod tests {
                     last_modified: Utc.with_ymd_and_hms(2024, 4, 14, 9, 47, 38).unwrap(),
                 },
                 number_of_plays: 0,
+                comment: None,
+                condition_text: None,
+                want_parts_list: None,
+                has_parts_list: None,
+                wishlist_comment: None,
                 stats: Some(CollectionItemStats {
                     min_players: 3,
                     max_players: 16,
@@ -1367,6 +2110,8 @@ mod tests {
                         ],
                     }
                 }),
+                private_info: None,
+                version: None,
             },
             "returned collection game doesn't match expected",
         );
@@ -1428,4 +2173,148 @@ mod tests {
 
         assert_eq!(collection.items.len(), 0);
     }
+
+    #[tokio::test]
+    async fn get_by_best_player_count() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let collection_mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("username".into(), "someone".into()),
+                Matcher::UrlEncoded("own".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/collection/collection_arnak.xml")
+                    .expect("failed to load test data"),
+            )
+            .expect(2)
+            .create_async()
+            .await;
+        let thing_mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "id".into(),
+                "312484,341254".into(),
+            )]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/thing/things_multiple.xml")
+                    .expect("failed to load test data"),
+            )
+            .expect(2)
+            .create_async()
+            .await;
+
+        let collection = api
+            .collection()
+            .get_by_best_player_count(
+                "someone",
+                2,
+                CollectionQueryParams::new().include_owned(true),
+            )
+            .await;
+
+        assert!(collection.is_ok(), "error returned when okay expected");
+        let ids: Vec<u64> = collection
+            .unwrap()
+            .items
+            .iter()
+            .map(|item| item.id)
+            .collect();
+        assert_eq!(ids, vec![341254]);
+
+        let collection = api
+            .collection()
+            .get_by_best_player_count(
+                "someone",
+                3,
+                CollectionQueryParams::new().include_owned(true),
+            )
+            .await;
+        collection_mock.assert_async().await;
+        thing_mock.assert_async().await;
+
+        assert!(collection.is_ok(), "error returned when okay expected");
+        let ids: Vec<u64> = collection
+            .unwrap()
+            .items
+            .iter()
+            .map(|item| item.id)
+            .collect();
+        assert_eq!(ids, vec![312484]);
+    }
+
+    #[tokio::test]
+    async fn get_by_recommended_player_count() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let collection_mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "username".into(),
+                "someone".into(),
+            )]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/collection/collection_arnak.xml")
+                    .expect("failed to load test data"),
+            )
+            .expect(2)
+            .create_async()
+            .await;
+        let thing_mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "id".into(),
+                "312484,341254".into(),
+            )]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/thing/things_multiple.xml")
+                    .expect("failed to load test data"),
+            )
+            .expect(2)
+            .create_async()
+            .await;
+
+        let collection = api
+            .collection_brief()
+            .get_by_recommended_player_count("someone", 1, CollectionQueryParams::new())
+            .await;
+
+        assert!(collection.is_ok(), "error returned when okay expected");
+        let ids: Vec<u64> = collection
+            .unwrap()
+            .items
+            .iter()
+            .map(|item| item.id)
+            .collect();
+        assert_eq!(ids, vec![312484, 341254]);
+
+        let collection = api
+            .collection_brief()
+            .get_by_recommended_player_count("someone", 4, CollectionQueryParams::new())
+            .await;
+        collection_mock.assert_async().await;
+        thing_mock.assert_async().await;
+
+        assert!(collection.is_ok(), "error returned when okay expected");
+        let ids: Vec<u64> = collection
+            .unwrap()
+            .items
+            .iter()
+            .map(|item| item.id)
+            .collect();
+        assert_eq!(ids, vec![312484]);
+    }
 }
@@ -0,0 +1,530 @@
+use serde::Deserialize;
+
+use crate::{BoardGameGeekApi, CollectionItemPrivateInfo, CollectionItemStatus, Error, Result};
+
+// The response from editing the collection, which contains an error message if the
+// change was not saved.
+#[derive(Deserialize)]
+struct CollectionEditResponse {
+    #[serde(default)]
+    error: Option<String>,
+}
+
+/// Collection editing endpoint of the site. Used for adding games to the logged in
+/// user's collection, and updating the details of the games already in it. All
+/// requests require a user to be logged in with [BoardGameGeekApi::login]. If the site
+/// reports that a change was not saved, such as for an unknown collection ID, the
+/// message is returned as [Error::UnknownApiErrors].
+///
+/// Games already in the collection are identified by their collection ID, which is
+/// returned from the collection endpoint as [crate::CollectionItem::collection_id].
+pub struct CollectionEditingApi<'api> {
+    pub(crate) api: &'api BoardGameGeekApi,
+    endpoint: &'static str,
+}
+
+impl<'api> CollectionEditingApi<'api> {
+    pub(crate) fn new(api: &'api BoardGameGeekApi) -> Self {
+        Self {
+            api,
+            endpoint: "geekcollection.php",
+        }
+    }
+
+    /// Adds a game to the logged in user's collection, by the ID of the game.
+    ///
+    /// The collection ID of the newly added item can be found by requesting the
+    /// user's collection afterwards.
+    pub async fn add(&self, item_id: u64) -> Result<()> {
+        self.send(vec![
+            ("ajax", "1".to_string()),
+            ("action", "additem".to_string()),
+            ("objecttype", "thing".to_string()),
+            ("objectid", item_id.to_string()),
+            ("force", "true".to_string()),
+        ])
+        .await
+    }
+
+    /// Removes an item from the logged in user's collection.
+    pub async fn delete(&self, collection_id: u64) -> Result<()> {
+        self.send(vec![
+            ("ajax", "1".to_string()),
+            ("action", "delete".to_string()),
+            ("collid", collection_id.to_string()),
+        ])
+        .await
+    }
+
+    /// Sets the status of an item in the collection, such as whether it is owned or
+    /// on the wishlist, along with the wishlist priority.
+    ///
+    /// The last_modified field of the status is ignored, as it is set by the site.
+    pub async fn set_status(
+        &self,
+        collection_id: u64,
+        status: &CollectionItemStatus,
+    ) -> Result<()> {
+        let flag = |value: bool| match value {
+            true => "1".to_string(),
+            false => "0".to_string(),
+        };
+        let mut form = vec![
+            ("own", flag(status.own)),
+            ("prevowned", flag(status.previously_owned)),
+            ("fortrade", flag(status.for_trade)),
+            ("want", flag(status.want_in_trade)),
+            ("wanttoplay", flag(status.want_to_play)),
+            ("wanttobuy", flag(status.want_to_buy)),
+            ("preordered", flag(status.pre_ordered)),
+            ("wishlist", flag(status.wishlist)),
+        ];
+        if let Some(wishlist_priority) = status.wishlist_priority {
+            form.push((
+                "wishlistpriority",
+                wishlist_priority.api_value().to_string(),
+            ));
+        }
+        self.save_data(collection_id, "status", form).await
+    }
+
+    /// Sets the user's 1-10 rating of an item in the collection, or clears it if None.
+    pub async fn set_rating(&self, collection_id: u64, rating: Option<f64>) -> Result<()> {
+        let rating = rating.map(|rating| rating.to_string()).unwrap_or_default();
+        self.save_data(collection_id, "rating", vec![("rating", rating)])
+            .await
+    }
+
+    /// Sets the user's comment on an item in the collection, or clears it if None.
+    pub async fn set_comment(&self, collection_id: u64, comment: Option<&str>) -> Result<()> {
+        self.save_text(collection_id, "comment", comment).await
+    }
+
+    /// Sets the user's comment on why an item is on their wishlist, or clears it if None.
+    pub async fn set_wishlist_comment(
+        &self,
+        collection_id: u64,
+        wishlist_comment: Option<&str>,
+    ) -> Result<()> {
+        self.save_text(collection_id, "wishlistcomment", wishlist_comment)
+            .await
+    }
+
+    /// Sets the description of the condition of the user's copy of an item, or clears
+    /// it if None.
+    pub async fn set_condition_text(
+        &self,
+        collection_id: u64,
+        condition_text: Option<&str>,
+    ) -> Result<()> {
+        self.save_text(collection_id, "conditiontext", condition_text)
+            .await
+    }
+
+    /// Sets the list of parts of an item that the user is missing and wants, or clears
+    /// it if None.
+    pub async fn set_want_parts_list(
+        &self,
+        collection_id: u64,
+        want_parts_list: Option<&str>,
+    ) -> Result<()> {
+        self.save_text(collection_id, "wantpartslist", want_parts_list)
+            .await
+    }
+
+    /// Sets the list of spare parts of an item that the user has, or clears it if None.
+    pub async fn set_has_parts_list(
+        &self,
+        collection_id: u64,
+        has_parts_list: Option<&str>,
+    ) -> Result<()> {
+        self.save_text(collection_id, "haspartslist", has_parts_list)
+            .await
+    }
+
+    /// Sets the private information about the user's copy of an item, such as the
+    /// price they paid for it. Any fields set to None are cleared.
+    pub async fn set_private_info(
+        &self,
+        collection_id: u64,
+        private_info: &CollectionItemPrivateInfo,
+    ) -> Result<()> {
+        let price_paid = private_info.price_paid.as_ref();
+        let current_value = private_info.current_value.as_ref();
+        let form = vec![
+            (
+                "pp_currency",
+                price_paid
+                    .map(|price| price.currency.clone())
+                    .unwrap_or_default(),
+            ),
+            (
+                "pricepaid",
+                price_paid.map(|price| price.amount()).unwrap_or_default(),
+            ),
+            (
+                "cv_currency",
+                current_value
+                    .map(|price| price.currency.clone())
+                    .unwrap_or_default(),
+            ),
+            (
+                "currvalue",
+                current_value
+                    .map(|price| price.amount())
+                    .unwrap_or_default(),
+            ),
+            (
+                "quantity",
+                private_info
+                    .quantity
+                    .map(|quantity| quantity.to_string())
+                    .unwrap_or_default(),
+            ),
+            (
+                "acquisitiondate",
+                private_info
+                    .acquisition_date
+                    .map(|date| date.format("%Y-%m-%d").to_string())
+                    .unwrap_or_default(),
+            ),
+            (
+                "acquiredfrom",
+                private_info.acquired_from.clone().unwrap_or_default(),
+            ),
+            (
+                "invlocation",
+                private_info.inventory_location.clone().unwrap_or_default(),
+            ),
+            (
+                "privatecomment",
+                private_info.private_comment.clone().unwrap_or_default(),
+            ),
+        ];
+        self.save_data(collection_id, "ownership", form).await
+    }
+
+    // Saves a free text field of an item in the collection.
+    async fn save_text(
+        &self,
+        collection_id: u64,
+        field_name: &'static str,
+        value: Option<&str>,
+    ) -> Result<()> {
+        let value = value.unwrap_or_default().to_string();
+        self.save_data(collection_id, field_name, vec![("value", value)])
+            .await
+    }
+
+    // Saves one of the groups of fields of an item in the collection, the site
+    // expects each group to be saved separately.
+    async fn save_data(
+        &self,
+        collection_id: u64,
+        field_name: &'static str,
+        fields: Vec<(&'static str, String)>,
+    ) -> Result<()> {
+        let mut form = vec![
+            ("ajax", "1".to_string()),
+            ("action", "savedata".to_string()),
+            ("collid", collection_id.to_string()),
+            ("fieldname", field_name.to_string()),
+        ];
+        form.extend(fields);
+        self.send(form).await
+    }
+
+    async fn send(&self, form: Vec<(&'static str, String)>) -> Result<()> {
+        let request = self.api.build_site_request(self.endpoint)?.form(&form);
+        let response = self.api.send_request(request).await?;
+        let response: CollectionEditResponse = response.json().await?;
+        if let Some(error) = response.error {
+            return Err(Error::UnknownApiErrors(vec![error]));
+        }
+        if let Some(cache) = self.api.cache() {
+            cache.invalidate_endpoint("collection");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use mockito::Matcher;
+
+    use super::*;
+    use crate::session::logged_in_api;
+    use crate::{CollectionQueryParams, Price};
+
+    #[tokio::test]
+    async fn add() {
+        let mut server = mockito::Server::new_async().await;
+        let api = logged_in_api(&server);
+
+        let mock = server
+            .mock("POST", "/geekcollection.php")
+            .match_header("cookie", "SessionID=xyz789")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("action".into(), "additem".into()),
+                Matcher::UrlEncoded("objecttype".into(), "thing".into()),
+                Matcher::UrlEncoded("objectid".into(), "312484".into()),
+            ]))
+            .with_status(200)
+            .with_body(r#"{"html":""}"#)
+            .create_async()
+            .await;
+
+        let res = api.collection_editing().add(312484).await;
+        mock.assert_async().await;
+
+        assert!(res.is_ok(), "error returned when okay expected");
+    }
+
+    #[tokio::test]
+    async fn set_status_from_collection() {
+        let mut server = mockito::Server::new_async().await;
+        let api = logged_in_api(&server);
+
+        let collection_mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/collection/collection_wishlist_single.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+        let collection = api
+            .collection()
+            .get_wishlist("somename")
+            .await
+            .expect("failed to get collection");
+        collection_mock.assert_async().await;
+
+        let item = &collection.items[0];
+        let mut status = item.status.clone();
+        status.own = true;
+
+        let mock = server
+            .mock("POST", "/geekcollection.php")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("action".into(), "savedata".into()),
+                Matcher::UrlEncoded("collid".into(), item.collection_id.to_string()),
+                Matcher::UrlEncoded("fieldname".into(), "status".into()),
+                Matcher::UrlEncoded("own".into(), "1".into()),
+                Matcher::UrlEncoded("prevowned".into(), "0".into()),
+                Matcher::UrlEncoded("wishlist".into(), "1".into()),
+                Matcher::UrlEncoded("wishlistpriority".into(), "2".into()),
+            ]))
+            .with_status(200)
+            .with_body(r#"{"html":""}"#)
+            .create_async()
+            .await;
+
+        let res = api
+            .collection_editing()
+            .set_status(item.collection_id, &status)
+            .await;
+        mock.assert_async().await;
+
+        assert!(res.is_ok(), "error returned when okay expected");
+    }
+
+    #[tokio::test]
+    async fn set_rating_and_comment() {
+        let mut server = mockito::Server::new_async().await;
+        let api = logged_in_api(&server);
+
+        let rating_mock = server
+            .mock("POST", "/geekcollection.php")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("collid".into(), "118332974".into()),
+                Matcher::UrlEncoded("fieldname".into(), "rating".into()),
+                Matcher::UrlEncoded("rating".into(), "8.5".into()),
+            ]))
+            .with_status(200)
+            .with_body(r#"{"html":""}"#)
+            .create_async()
+            .await;
+        let comment_mock = server
+            .mock("POST", "/geekcollection.php")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("collid".into(), "118332974".into()),
+                Matcher::UrlEncoded("fieldname".into(), "comment".into()),
+                Matcher::UrlEncoded("value".into(), "Great solo game.".into()),
+            ]))
+            .with_status(200)
+            .with_body(r#"{"html":""}"#)
+            .create_async()
+            .await;
+
+        let editing = api.collection_editing();
+        let rating_res = editing.set_rating(118332974, Some(8.5)).await;
+        let comment_res = editing
+            .set_comment(118332974, Some("Great solo game."))
+            .await;
+        rating_mock.assert_async().await;
+        comment_mock.assert_async().await;
+
+        assert!(rating_res.is_ok(), "error returned when okay expected");
+        assert!(comment_res.is_ok(), "error returned when okay expected");
+    }
+
+    #[tokio::test]
+    async fn set_private_info() {
+        let mut server = mockito::Server::new_async().await;
+        let api = logged_in_api(&server);
+
+        let mock = server
+            .mock("POST", "/geekcollection.php")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("collid".into(), "118332901".into()),
+                Matcher::UrlEncoded("fieldname".into(), "ownership".into()),
+                Matcher::UrlEncoded("pp_currency".into(), "GBP".into()),
+                Matcher::UrlEncoded("pricepaid".into(), "45.50".into()),
+                Matcher::UrlEncoded("cv_currency".into(), "".into()),
+                Matcher::UrlEncoded("currvalue".into(), "".into()),
+                Matcher::UrlEncoded("quantity".into(), "1".into()),
+                Matcher::UrlEncoded("acquisitiondate".into(), "2021-03-05".into()),
+                Matcher::UrlEncoded("acquiredfrom".into(), "Forbidden Planet".into()),
+                Matcher::UrlEncoded("invlocation".into(), "".into()),
+                Matcher::UrlEncoded("privatecomment".into(), "Signed.".into()),
+            ]))
+            .with_status(200)
+            .with_body(r#"{"html":""}"#)
+            .create_async()
+            .await;
+
+        let private_info = CollectionItemPrivateInfo {
+            price_paid: Some(Price {
+                minor_units: 4550,
+                currency: "GBP".into(),
+            }),
+            current_value: None,
+            quantity: Some(1),
+            acquisition_date: NaiveDate::from_ymd_opt(2021, 3, 5),
+            acquired_from: Some("Forbidden Planet".into()),
+            inventory_location: None,
+            private_comment: Some("Signed.".into()),
+        };
+        let res = api
+            .collection_editing()
+            .set_private_info(118332901, &private_info)
+            .await;
+        mock.assert_async().await;
+
+        assert!(res.is_ok(), "error returned when okay expected");
+    }
+
+    #[tokio::test]
+    async fn set_private_info_from_collection() {
+        let mut server = mockito::Server::new_async().await;
+        let api = logged_in_api(&server);
+
+        let collection_mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::UrlEncoded("showprivate".into(), "1".into()))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/collection/collection_owned_private.xml")
+                    .expect("failed to load test data"),
+            )
+            .expect(2)
+            .create_async()
+            .await;
+        let query_params = CollectionQueryParams::new().show_private(true);
+        let collection = api
+            .collection()
+            .get_from_query("somename", query_params.clone())
+            .await
+            .expect("failed to get collection");
+
+        let item = &collection.items[0];
+        let private_info = item
+            .private_info
+            .clone()
+            .expect("private info expected in collection");
+
+        // The private info read from the collection should be sent back unchanged.
+        let mock = server
+            .mock("POST", "/geekcollection.php")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("collid".into(), item.collection_id.to_string()),
+                Matcher::UrlEncoded("fieldname".into(), "ownership".into()),
+                Matcher::UrlEncoded("pp_currency".into(), "GBP".into()),
+                Matcher::UrlEncoded("pricepaid".into(), "45.50".into()),
+                Matcher::UrlEncoded("cv_currency".into(), "GBP".into()),
+                Matcher::UrlEncoded("currvalue".into(), "50.00".into()),
+                Matcher::UrlEncoded("quantity".into(), "1".into()),
+                Matcher::UrlEncoded("acquisitiondate".into(), "2021-03-05".into()),
+                Matcher::UrlEncoded("acquiredfrom".into(), "Forbidden Planet".into()),
+                Matcher::UrlEncoded("invlocation".into(), "Shelf 2".into()),
+                Matcher::UrlEncoded(
+                    "privatecomment".into(),
+                    "Signed by the designers at UKGE.".into(),
+                ),
+            ]))
+            .with_status(200)
+            .with_body(r#"{"html":""}"#)
+            .create_async()
+            .await;
+
+        let res = api
+            .collection_editing()
+            .set_private_info(item.collection_id, &private_info)
+            .await;
+        mock.assert_async().await;
+        assert!(res.is_ok(), "error returned when okay expected");
+
+        // Reading the collection back returns the same private info that was saved.
+        let collection = api
+            .collection()
+            .get_from_query("somename", query_params)
+            .await
+            .expect("failed to get collection");
+        collection_mock.assert_async().await;
+
+        assert_eq!(collection.items[0].private_info, Some(private_info));
+    }
+
+    #[tokio::test]
+    async fn set_comment_error() {
+        let mut server = mockito::Server::new_async().await;
+        let api = logged_in_api(&server);
+
+        let mock = server
+            .mock("POST", "/geekcollection.php")
+            .with_status(200)
+            .with_body(r#"{"error":"Invalid collection item."}"#)
+            .create_async()
+            .await;
+
+        let res = api
+            .collection_editing()
+            .set_comment(0, Some("Great solo game."))
+            .await;
+        mock.assert_async().await;
+
+        match res {
+            Err(Error::UnknownApiErrors(messages)) => {
+                assert_eq!(messages, vec!["Invalid collection item."]);
+            }
+            _ => panic!("unknown API error expected"),
+        }
+    }
+
+    #[tokio::test]
+    async fn add_not_logged_in() {
+        let server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            session: None,
+            ..logged_in_api(&server)
+        };
+
+        let res = api.collection_editing().add(312484).await;
+
+        assert!(matches!(res, Err(Error::NotLoggedInError)));
+    }
+}
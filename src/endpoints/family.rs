@@ -0,0 +1,373 @@
+use core::fmt;
+
+use serde::Deserialize;
+
+use super::Link;
+use crate::utils::{NameType, XmlName};
+use crate::{BoardGameGeekApi, Error, Result};
+
+/// The returned struct containing a list of families.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Families {
+    /// The list of families returned.
+    #[serde(default, rename = "$value")]
+    pub items: Vec<Family>,
+}
+
+/// A family of related items, such as "Series: Pandemic" or "Mechanism: Legacy".
+#[derive(Clone, Debug, PartialEq)]
+pub struct Family {
+    /// The ID of the family.
+    pub id: u64,
+    /// The type of family.
+    pub family_type: FamilyType,
+    /// The primary name of the family.
+    pub name: String,
+    /// Any other names the family is known by.
+    pub alternate_names: Vec<String>,
+    /// The description of the family.
+    pub description: String,
+    /// A link to a jpg image for the family. Omitted if the family has no image.
+    pub image: Option<String>,
+    /// A link to a jpg thumbnail image for the family. Omitted if the family has no image.
+    pub thumbnail: Option<String>,
+    /// Links to the items in the family. These are all inbound links, from the item to
+    /// this family.
+    pub links: Vec<Link>,
+}
+
+impl<'de> Deserialize<'de> for Family {
+    fn deserialize<D: serde::de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(field_identifier, rename_all = "lowercase")]
+        enum Field {
+            ID,
+            Type,
+            Name,
+            Description,
+            Image,
+            Thumbnail,
+            Link,
+            #[serde(other)]
+            Unknown,
+        }
+
+        struct FamilyVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for FamilyVisitor {
+            type Value = Family;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a string containing the XML for a family.")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> core::result::Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut id = None;
+                let mut family_type = None;
+                let mut name = None;
+                let mut alternate_names = vec![];
+                let mut description = None;
+                let mut image = None;
+                let mut thumbnail = None;
+                let mut links = vec![];
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Field::ID => {
+                            if id.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            let id_str: String = map.next_value()?;
+                            id = Some(id_str.parse::<u64>().map_err(|e| {
+                                serde::de::Error::custom(format!(
+                                    "failed to parse value a u64: {e}"
+                                ))
+                            })?);
+                        }
+                        Field::Type => {
+                            if family_type.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            family_type = Some(map.next_value()?);
+                        }
+                        Field::Name => {
+                            let name_xml: XmlName = map.next_value()?;
+                            match name_xml.name_type {
+                                NameType::Primary => {
+                                    if name.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("name"));
+                                    }
+                                    name = Some(name_xml.value);
+                                }
+                                NameType::Alternate => {
+                                    alternate_names.push(name_xml.value);
+                                }
+                            }
+                        }
+                        Field::Description => {
+                            if description.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description = Some(map.next_value()?);
+                        }
+                        Field::Image => {
+                            if image.is_some() {
+                                return Err(serde::de::Error::duplicate_field("image"));
+                            }
+                            image = Some(map.next_value()?);
+                        }
+                        Field::Thumbnail => {
+                            if thumbnail.is_some() {
+                                return Err(serde::de::Error::duplicate_field("thumbnail"));
+                            }
+                            thumbnail = Some(map.next_value()?);
+                        }
+                        Field::Link => {
+                            links.push(map.next_value()?);
+                        }
+                        Field::Unknown => {
+                            map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let id = id.ok_or_else(|| serde::de::Error::missing_field("id"))?;
+                let family_type =
+                    family_type.ok_or_else(|| serde::de::Error::missing_field("type"))?;
+                let name = name.ok_or_else(|| serde::de::Error::missing_field("name"))?;
+                let description =
+                    description.ok_or_else(|| serde::de::Error::missing_field("description"))?;
+                Ok(Self::Value {
+                    id,
+                    family_type,
+                    name,
+                    alternate_names,
+                    description,
+                    image,
+                    thumbnail,
+                    links,
+                })
+            }
+        }
+        deserializer.deserialize_any(FamilyVisitor)
+    }
+}
+
+/// The type of family.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub enum FamilyType {
+    /// A family of board games.
+    #[serde(rename = "boardgamefamily")]
+    BoardGameFamily,
+    /// A family of role playing games.
+    #[serde(rename = "rpg")]
+    Rpg,
+    /// A role playing game periodical.
+    #[serde(rename = "rpgperiodical")]
+    RpgPeriodical,
+}
+
+/// Required query paramters.
+#[derive(Clone, Debug)]
+pub struct BaseFamilyQuery<'q> {
+    pub(crate) ids: &'q [u64],
+}
+
+/// All optional query parameters for making a request to the
+/// family endpoint.
+#[derive(Clone, Debug, Default)]
+pub struct FamilyQueryParams {
+    /// Include only results for this family type.
+    family_type: Option<FamilyType>,
+}
+
+impl FamilyQueryParams {
+    /// Constructs a new family query with parameters set to None.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the family_type field, so that only that type of family will be returned.
+    pub fn family_type(mut self, family_type: FamilyType) -> Self {
+        self.family_type = Some(family_type);
+        self
+    }
+}
+
+/// Struct for building a query for the request to the family endpoint.
+#[derive(Clone, Debug)]
+struct FamilyQueryBuilder<'q> {
+    base: BaseFamilyQuery<'q>,
+    params: FamilyQueryParams,
+}
+
+impl<'builder> FamilyQueryBuilder<'builder> {
+    /// Constructs a new query builder from a base query, and the rest of the parameters.
+    fn new(base: BaseFamilyQuery<'builder>, params: FamilyQueryParams) -> Self {
+        Self { base, params }
+    }
+
+    pub fn build(self) -> Vec<(&'builder str, String)> {
+        let mut query_params: Vec<_> = vec![];
+        let ids: Vec<String> = self.base.ids.iter().map(u64::to_string).collect();
+        query_params.push(("id", ids.join(",")));
+
+        match self.params.family_type {
+            Some(FamilyType::BoardGameFamily) => {
+                query_params.push(("type", "boardgamefamily".to_string()))
+            }
+            Some(FamilyType::Rpg) => query_params.push(("type", "rpg".to_string())),
+            Some(FamilyType::RpgPeriodical) => {
+                query_params.push(("type", "rpgperiodical".to_string()))
+            }
+            None => {}
+        }
+        query_params
+    }
+}
+
+/// Family endpoint of the API. Used for returning families of related items,
+/// along with links to all the items in them.
+pub struct FamilyApi<'api> {
+    pub(crate) api: &'api BoardGameGeekApi,
+    endpoint: &'static str,
+}
+
+impl<'api> FamilyApi<'api> {
+    pub(crate) fn new(api: &'api BoardGameGeekApi) -> Self {
+        Self {
+            api,
+            endpoint: "family",
+        }
+    }
+
+    /// Gets a family by its ID.
+    pub async fn get(&self, id: u64) -> Result<Family> {
+        self.get_from_query(id, FamilyQueryParams::new()).await
+    }
+
+    /// Makes a request for a single family from a [FamilyQueryParams].
+    pub async fn get_from_query(&self, id: u64, query_params: FamilyQueryParams) -> Result<Family> {
+        let families = self.get_many_from_query(&[id], query_params).await?;
+        families
+            .items
+            .into_iter()
+            .find(|family| family.id == id)
+            .ok_or(Error::ItemNotFoundError(id))
+    }
+
+    /// Makes a request for many families from a [FamilyQueryParams]. Any IDs that
+    /// are not found are omitted from the result.
+    pub async fn get_many_from_query(
+        &self,
+        ids: &[u64],
+        query_params: FamilyQueryParams,
+    ) -> Result<Families> {
+        let query = FamilyQueryBuilder::new(BaseFamilyQuery { ids }, query_params);
+
+        let request = self.api.build_request(self.endpoint, &query.build());
+        self.api.execute_request::<Families>(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use super::*;
+    use crate::LinkType;
+
+    #[tokio::test]
+    async fn get() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/family")
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "id".into(),
+                "3430".into(),
+            )]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/family/family.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let family = api.family().get(3430).await;
+        mock.assert_async().await;
+
+        assert!(family.is_ok(), "error returned when okay expected");
+        assert_eq!(
+            family.unwrap(),
+            Family {
+                id: 3430,
+                family_type: FamilyType::BoardGameFamily,
+                name: "Series: Pandemic".into(),
+                alternate_names: vec!["Pandemic (Z-Man Games)".into()],
+                description: "Games in the Pandemic series, where players work together to stop the spread of disease.".into(),
+                image: Some("https://cf.geekdo-images.com/S3ybV1LAp-8SnHIXLLjVqA__original/img/IsrvRLpUV1TEyZsO5rC-btXaPz0=/0x0/filters:format(jpeg)/pic1534148.jpg".into()),
+                thumbnail: Some("https://cf.geekdo-images.com/S3ybV1LAp-8SnHIXLLjVqA__thumb/img/a2e1ea3zVOmMmuPIaPBJPp3QNGI=/fit-in/200x150/filters:strip_icc()/pic1534148.jpg".into()),
+                links: vec![
+                    Link {
+                        link_type: LinkType::Family,
+                        id: 30549,
+                        name: "Pandemic".into(),
+                        inbound: true,
+                    },
+                    Link {
+                        link_type: LinkType::Family,
+                        id: 161936,
+                        name: "Pandemic Legacy: Season 1".into(),
+                        inbound: true,
+                    },
+                    Link {
+                        link_type: LinkType::Family,
+                        id: 221107,
+                        name: "Pandemic Legacy: Season 2".into(),
+                        inbound: true,
+                    },
+                ],
+            },
+        );
+    }
+
+    #[tokio::test]
+    async fn get_from_query() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/family")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".into(), "3430".into()),
+                Matcher::UrlEncoded("type".into(), "rpg".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/family/family_not_found.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let family = api
+            .family()
+            .get_from_query(3430, FamilyQueryParams::new().family_type(FamilyType::Rpg))
+            .await;
+        mock.assert_async().await;
+
+        assert!(matches!(family, Err(Error::ItemNotFoundError(3430))));
+    }
+}
@@ -0,0 +1,292 @@
+use chrono::{DateTime, Utc};
+use futures_util::Stream;
+use serde::Deserialize;
+
+use crate::utils::{deserialize_1_0_bool, rfc2822_date_deserializer, stream_pages};
+use crate::{BoardGameGeekApi, Result};
+
+/// A forum, along with a page of the threads in it.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Forum {
+    /// The ID of the forum.
+    pub id: u64,
+    /// The title of the forum, such as "Rules" or "Reviews".
+    pub title: String,
+    /// The total number of threads in the forum across all pages.
+    #[serde(rename = "numthreads")]
+    pub number_of_threads: u64,
+    /// The number of posts across all threads in the forum.
+    #[serde(rename = "numposts")]
+    pub number_of_posts: u64,
+    /// The date and time of the most recent post in the forum. This is the Unix epoch
+    /// if there have been no posts.
+    #[serde(rename = "lastpostdate", with = "rfc2822_date_deserializer")]
+    pub last_post_date: DateTime<Utc>,
+    /// Whether new threads can not be posted in this forum.
+    #[serde(rename = "noposting", deserialize_with = "deserialize_1_0_bool")]
+    pub no_posting: bool,
+    /// The threads on this page, most recently active first.
+    #[serde(default, deserialize_with = "deserialize_threads")]
+    pub threads: Vec<ThreadSummary>,
+}
+
+/// A thread in a [Forum]. The articles in the thread can be retrieved using the
+/// thread endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct ThreadSummary {
+    /// The ID of the thread.
+    pub id: u64,
+    /// The subject of the thread.
+    pub subject: String,
+    /// The username of the user who started the thread.
+    pub author: String,
+    /// The number of articles in the thread, including the first.
+    #[serde(rename = "numarticles")]
+    pub number_of_articles: u64,
+    /// The date and time the thread was started.
+    #[serde(rename = "postdate", with = "rfc2822_date_deserializer")]
+    pub post_date: DateTime<Utc>,
+    /// The date and time of the most recent article in the thread.
+    #[serde(rename = "lastpostdate", with = "rfc2822_date_deserializer")]
+    pub last_post_date: DateTime<Utc>,
+}
+
+// Intermediary struct needed due to the way the XML is structured.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+struct Threads {
+    #[serde(default, rename = "$value")]
+    threads: Vec<ThreadSummary>,
+}
+
+fn deserialize_threads<'de, D>(
+    deserializer: D,
+) -> core::result::Result<Vec<ThreadSummary>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let threads: Threads = Deserialize::deserialize(deserializer)?;
+    Ok(threads.threads)
+}
+
+/// Required query paramters.
+#[derive(Clone, Debug)]
+pub struct BaseForumQuery {
+    pub(crate) id: u64,
+}
+
+/// All optional query parameters for making a request to the
+/// forum endpoint.
+#[derive(Clone, Debug, Default)]
+pub struct ForumQueryParams {
+    /// The page of threads to return, starting from 1.
+    page: Option<u64>,
+}
+
+impl ForumQueryParams {
+    /// Constructs a new forum query with parameters set to None.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the page field, for which page of threads to return. Pages start from 1.
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
+/// Struct for building a query for the request to the forum endpoint.
+#[derive(Clone, Debug)]
+struct ForumQueryBuilder {
+    base: BaseForumQuery,
+    params: ForumQueryParams,
+}
+
+impl ForumQueryBuilder {
+    /// Constructs a new query builder from a base query, and the rest of the parameters.
+    fn new(base: BaseForumQuery, params: ForumQueryParams) -> Self {
+        Self { base, params }
+    }
+
+    pub fn build(self) -> Vec<(&'static str, String)> {
+        let mut query_params: Vec<_> = vec![];
+        query_params.push(("id", self.base.id.to_string()));
+
+        if let Some(page) = self.params.page {
+            query_params.push(("page", page.to_string()));
+        }
+        query_params
+    }
+}
+
+/// Forum endpoint of the API. Used for returning the threads in a forum.
+pub struct ForumApi<'api> {
+    pub(crate) api: &'api BoardGameGeekApi,
+    endpoint: &'static str,
+}
+
+impl<'api> ForumApi<'api> {
+    // The number of threads the API returns per page.
+    const PAGE_SIZE: u64 = 50;
+
+    pub(crate) fn new(api: &'api BoardGameGeekApi) -> Self {
+        Self {
+            api,
+            endpoint: "forum",
+        }
+    }
+
+    /// Gets a forum by its ID, along with the first page of threads.
+    pub async fn get(&self, id: u64) -> Result<Forum> {
+        self.get_from_query(id, ForumQueryParams::new()).await
+    }
+
+    /// Makes a request for a forum from a [ForumQueryParams].
+    pub async fn get_from_query(&self, id: u64, query_params: ForumQueryParams) -> Result<Forum> {
+        let query = ForumQueryBuilder::new(BaseForumQuery { id }, query_params);
+
+        let request = self.api.build_request(self.endpoint, &query.build());
+        self.api.execute_request::<Forum>(request).await
+    }
+
+    /// Gets all of the threads in a forum.
+    ///
+    /// The threads are returned as a stream which requests each page in turn as it is
+    /// needed, starting from the page in the query, and ends once the total number of
+    /// threads reported by the API have been returned. If a request fails the error is
+    /// returned and the stream ends.
+    pub fn threads(
+        &self,
+        id: u64,
+        query_params: ForumQueryParams,
+    ) -> impl Stream<Item = Result<ThreadSummary>> + 'api {
+        let api = self.api;
+        let first_page = query_params.page.unwrap_or(1);
+
+        stream_pages(first_page, Self::PAGE_SIZE, move |page| {
+            let query_params = query_params.clone();
+            async move {
+                let forum = ForumApi::new(api)
+                    .get_from_query(id, query_params.page(page))
+                    .await?;
+                Ok((forum.threads, forum.number_of_threads))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use futures_util::StreamExt;
+    use mockito::Matcher;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn get() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/forum")
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "id".into(),
+                "2527476".into(),
+            )]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/forum/forum.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let forum = api.forum().get(2527476).await;
+        mock.assert_async().await;
+
+        assert!(forum.is_ok(), "error returned when okay expected");
+        assert_eq!(
+            forum.unwrap(),
+            Forum {
+                id: 2527476,
+                title: "Rules".into(),
+                number_of_threads: 51,
+                number_of_posts: 1893,
+                last_post_date: Utc.with_ymd_and_hms(2024, 4, 13, 21, 2, 44).unwrap(),
+                no_posting: false,
+                threads: vec![
+                    ThreadSummary {
+                        id: 3254021,
+                        subject: "Can you use a guardian's boon on the turn you defeat it?".into(),
+                        author: "cardboardsam".into(),
+                        number_of_articles: 4,
+                        post_date: Utc.with_ymd_and_hms(2024, 4, 12, 19, 45, 1).unwrap(),
+                        last_post_date: Utc.with_ymd_and_hms(2024, 4, 13, 21, 2, 44).unwrap(),
+                    },
+                    ThreadSummary {
+                        id: 3250897,
+                        subject: "Research track & temple tiles".into(),
+                        author: "meeplequeen".into(),
+                        number_of_articles: 2,
+                        post_date: Utc.with_ymd_and_hms(2024, 4, 8, 12, 10, 33).unwrap(),
+                        last_post_date: Utc.with_ymd_and_hms(2024, 4, 8, 14, 51, 20).unwrap(),
+                    },
+                ],
+            },
+        );
+    }
+
+    #[tokio::test]
+    async fn threads() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let first_mock = server
+            .mock("GET", "/forum")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".into(), "2527476".into()),
+                Matcher::UrlEncoded("page".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/forum/forum.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+        let second_mock = server
+            .mock("GET", "/forum")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".into(), "2527476".into()),
+                Matcher::UrlEncoded("page".into(), "2".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/forum/forum_page_2.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let threads: Vec<Result<ThreadSummary>> = api
+            .forum()
+            .threads(2527476, ForumQueryParams::new())
+            .collect()
+            .await;
+        first_mock.assert_async().await;
+        second_mock.assert_async().await;
+
+        let ids: Vec<u64> = threads
+            .into_iter()
+            .map(|thread| thread.unwrap().id)
+            .collect();
+        assert_eq!(ids, vec![3254021, 3250897, 2511344]);
+    }
+}
@@ -0,0 +1,178 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::utils::{deserialize_1_0_bool, rfc2822_date_deserializer};
+use crate::{BoardGameGeekApi, Result};
+
+/// The list of forums attached to a thing or a family.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct ForumList {
+    /// The ID of the thing or family the forums are attached to.
+    #[serde(rename = "id")]
+    pub item_id: u64,
+    /// Whether the forums are attached to a thing or a family.
+    #[serde(rename = "type")]
+    pub list_type: ForumListType,
+    /// The forums attached to the item.
+    #[serde(default, rename = "$value")]
+    pub forums: Vec<ForumSummary>,
+}
+
+/// A forum in a [ForumList]. The threads in the forum can be retrieved using the
+/// forum endpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct ForumSummary {
+    /// The ID of the forum.
+    pub id: u64,
+    /// The ID of the group the forum belongs to, 0 if it is not in a group.
+    #[serde(rename = "groupid")]
+    pub group_id: u64,
+    /// The title of the forum, such as "Rules" or "Reviews".
+    pub title: String,
+    /// Whether new threads can not be posted in this forum.
+    #[serde(rename = "noposting", deserialize_with = "deserialize_1_0_bool")]
+    pub no_posting: bool,
+    /// The description of the forum.
+    pub description: String,
+    /// The number of threads in the forum.
+    #[serde(rename = "numthreads")]
+    pub number_of_threads: u64,
+    /// The number of posts across all threads in the forum.
+    #[serde(rename = "numposts")]
+    pub number_of_posts: u64,
+    /// The date and time of the most recent post in the forum. This is the Unix epoch
+    /// if there have been no posts.
+    #[serde(rename = "lastpostdate", with = "rfc2822_date_deserializer")]
+    pub last_post_date: DateTime<Utc>,
+}
+
+/// The type of item that a list of forums is attached to.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub enum ForumListType {
+    /// A thing, such as a board game.
+    #[serde(rename = "thing")]
+    Thing,
+    /// A family of things.
+    #[serde(rename = "family")]
+    Family,
+}
+
+/// Required query paramters.
+#[derive(Clone, Debug)]
+pub struct BaseForumListQuery {
+    pub(crate) id: u64,
+    pub(crate) list_type: ForumListType,
+}
+
+/// Struct for building a query for the request to the forum list endpoint.
+#[derive(Clone, Debug)]
+struct ForumListQueryBuilder {
+    base: BaseForumListQuery,
+}
+
+impl ForumListQueryBuilder {
+    /// Constructs a new query builder from a base query.
+    fn new(base: BaseForumListQuery) -> Self {
+        Self { base }
+    }
+
+    pub fn build(self) -> Vec<(&'static str, String)> {
+        let mut query_params: Vec<_> = vec![];
+        query_params.push(("id", self.base.id.to_string()));
+
+        match self.base.list_type {
+            ForumListType::Thing => query_params.push(("type", "thing".to_string())),
+            ForumListType::Family => query_params.push(("type", "family".to_string())),
+        }
+        query_params
+    }
+}
+
+/// Forum list endpoint of the API. Used for returning the forums attached to a thing
+/// or a family.
+pub struct ForumListApi<'api> {
+    pub(crate) api: &'api BoardGameGeekApi,
+    endpoint: &'static str,
+}
+
+impl<'api> ForumListApi<'api> {
+    pub(crate) fn new(api: &'api BoardGameGeekApi) -> Self {
+        Self {
+            api,
+            endpoint: "forumlist",
+        }
+    }
+
+    /// Gets the forums attached to a thing, such as a board game.
+    pub async fn get_for_thing(&self, id: u64) -> Result<ForumList> {
+        self.get(id, ForumListType::Thing).await
+    }
+
+    /// Gets the forums attached to a family.
+    pub async fn get_for_family(&self, id: u64) -> Result<ForumList> {
+        self.get(id, ForumListType::Family).await
+    }
+
+    /// Gets the forums attached to an item of the given type.
+    pub async fn get(&self, id: u64, list_type: ForumListType) -> Result<ForumList> {
+        let query = ForumListQueryBuilder::new(BaseForumListQuery { id, list_type });
+
+        let request = self.api.build_request(self.endpoint, &query.build());
+        self.api.execute_request::<ForumList>(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use mockito::Matcher;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn get_for_thing() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/forumlist")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".into(), "312484".into()),
+                Matcher::UrlEncoded("type".into(), "thing".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/forum_list/forum_list.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let forum_list = api.forum_list().get_for_thing(312484).await;
+        mock.assert_async().await;
+
+        assert!(forum_list.is_ok(), "error returned when okay expected");
+        let forum_list = forum_list.unwrap();
+
+        assert_eq!(forum_list.item_id, 312484);
+        assert_eq!(forum_list.list_type, ForumListType::Thing);
+        assert_eq!(forum_list.forums.len(), 3);
+        assert_eq!(
+            forum_list.forums[1],
+            ForumSummary {
+                id: 2527476,
+                group_id: 0,
+                title: "Rules".into(),
+                no_posting: false,
+                description: "Post any rules questions you have here.".into(),
+                number_of_threads: 51,
+                number_of_posts: 1893,
+                last_post_date: Utc.with_ymd_and_hms(2024, 4, 13, 21, 2, 44).unwrap(),
+            },
+        );
+        assert!(forum_list.forums[2].no_posting);
+    }
+}
@@ -0,0 +1,458 @@
+use core::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::utils::{deserialize_optional_id, rfc2822_date_deserializer};
+use crate::{BoardGameGeekApi, Result};
+
+/// A user created list of items on the site, each with a description by the
+/// list's owner.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeekList {
+    /// The ID of the geeklist.
+    pub id: u64,
+    /// The title of the geeklist.
+    pub title: String,
+    /// The username of the user who created the geeklist.
+    pub username: String,
+    /// The date and time the geeklist was created.
+    pub post_date: DateTime<Utc>,
+    /// The date and time the geeklist was last edited.
+    pub edit_date: DateTime<Utc>,
+    /// The number of thumbs up the geeklist has received.
+    pub thumbs: u64,
+    /// The number of items in the geeklist.
+    pub number_of_items: u64,
+    /// The description of the geeklist.
+    pub description: String,
+    /// Comments on the geeklist as a whole. Only included if requested in the query.
+    pub comments: Vec<GeekListComment>,
+    /// The items in the geeklist, in the order they appear in the list.
+    pub items: Vec<GeekListItem>,
+}
+
+impl<'de> Deserialize<'de> for GeekList {
+    fn deserialize<D: serde::de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(field_identifier, rename_all = "lowercase")]
+        enum Field {
+            ID,
+            Title,
+            Username,
+            PostDate,
+            EditDate,
+            Thumbs,
+            NumItems,
+            Description,
+            Comment,
+            Item,
+            #[serde(other)]
+            Unknown,
+        }
+
+        struct GeekListVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeekListVisitor {
+            type Value = GeekList;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a string containing the XML for a geeklist.")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> core::result::Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut id = None;
+                let mut title = None;
+                let mut username = None;
+                let mut post_date = None;
+                let mut edit_date = None;
+                let mut thumbs = None;
+                let mut number_of_items = None;
+                let mut description = None;
+                let mut comments = vec![];
+                let mut items = vec![];
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Field::ID => {
+                            if id.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            let id_str: String = map.next_value()?;
+                            id = Some(id_str.parse::<u64>().map_err(|e| {
+                                serde::de::Error::custom(format!(
+                                    "failed to parse value a u64: {e}"
+                                ))
+                            })?);
+                        }
+                        Field::Title => {
+                            if title.is_some() {
+                                return Err(serde::de::Error::duplicate_field("title"));
+                            }
+                            title = Some(map.next_value()?);
+                        }
+                        Field::Username => {
+                            if username.is_some() {
+                                return Err(serde::de::Error::duplicate_field("username"));
+                            }
+                            username = Some(map.next_value()?);
+                        }
+                        Field::PostDate => {
+                            if post_date.is_some() {
+                                return Err(serde::de::Error::duplicate_field("postdate"));
+                            }
+                            let date: String = map.next_value()?;
+                            post_date = Some(
+                                rfc2822_date_deserializer::parse(&date)
+                                    .map_err(serde::de::Error::custom)?,
+                            );
+                        }
+                        Field::EditDate => {
+                            if edit_date.is_some() {
+                                return Err(serde::de::Error::duplicate_field("editdate"));
+                            }
+                            let date: String = map.next_value()?;
+                            edit_date = Some(
+                                rfc2822_date_deserializer::parse(&date)
+                                    .map_err(serde::de::Error::custom)?,
+                            );
+                        }
+                        Field::Thumbs => {
+                            if thumbs.is_some() {
+                                return Err(serde::de::Error::duplicate_field("thumbs"));
+                            }
+                            let thumbs_str: String = map.next_value()?;
+                            thumbs = Some(thumbs_str.parse::<u64>().map_err(|e| {
+                                serde::de::Error::custom(format!(
+                                    "failed to parse value a u64: {e}"
+                                ))
+                            })?);
+                        }
+                        Field::NumItems => {
+                            if number_of_items.is_some() {
+                                return Err(serde::de::Error::duplicate_field("numitems"));
+                            }
+                            let number_of_items_str: String = map.next_value()?;
+                            number_of_items =
+                                Some(number_of_items_str.parse::<u64>().map_err(|e| {
+                                    serde::de::Error::custom(format!(
+                                        "failed to parse value a u64: {e}"
+                                    ))
+                                })?);
+                        }
+                        Field::Description => {
+                            if description.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description = Some(map.next_value()?);
+                        }
+                        Field::Comment => {
+                            comments.push(map.next_value()?);
+                        }
+                        Field::Item => {
+                            items.push(map.next_value()?);
+                        }
+                        Field::Unknown => {
+                            map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let id = id.ok_or_else(|| serde::de::Error::missing_field("id"))?;
+                let title = title.ok_or_else(|| serde::de::Error::missing_field("title"))?;
+                let username =
+                    username.ok_or_else(|| serde::de::Error::missing_field("username"))?;
+                let post_date =
+                    post_date.ok_or_else(|| serde::de::Error::missing_field("postdate"))?;
+                let edit_date =
+                    edit_date.ok_or_else(|| serde::de::Error::missing_field("editdate"))?;
+                let thumbs = thumbs.ok_or_else(|| serde::de::Error::missing_field("thumbs"))?;
+                let number_of_items =
+                    number_of_items.ok_or_else(|| serde::de::Error::missing_field("numitems"))?;
+                let description = description.unwrap_or_default();
+                Ok(Self::Value {
+                    id,
+                    title,
+                    username,
+                    post_date,
+                    edit_date,
+                    thumbs,
+                    number_of_items,
+                    description,
+                    comments,
+                    items,
+                })
+            }
+        }
+        deserializer.deserialize_any(GeekListVisitor)
+    }
+}
+
+/// An entry in a [GeekList], pointing to an object on the site such as a game.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct GeekListItem {
+    /// The ID of the entry in the geeklist.
+    pub id: u64,
+    /// The type of object the entry is for.
+    #[serde(rename = "objecttype")]
+    pub object_type: GeekListObjectType,
+    /// The subtype of the object, such as "boardgame" or "boardgamedesigner".
+    pub subtype: String,
+    /// The ID of the object the entry is for.
+    #[serde(rename = "objectid")]
+    pub object_id: u64,
+    /// The name of the object the entry is for.
+    #[serde(rename = "objectname")]
+    pub object_name: String,
+    /// The username of the user who added the entry.
+    pub username: String,
+    /// The date and time the entry was added.
+    #[serde(rename = "postdate", with = "rfc2822_date_deserializer")]
+    pub post_date: DateTime<Utc>,
+    /// The date and time the entry was last edited.
+    #[serde(rename = "editdate", with = "rfc2822_date_deserializer")]
+    pub edit_date: DateTime<Utc>,
+    /// The number of thumbs up the entry has received.
+    pub thumbs: u64,
+    /// The ID of the image shown with the entry, if one was chosen.
+    #[serde(
+        default,
+        rename = "imageid",
+        deserialize_with = "deserialize_optional_id"
+    )]
+    pub image_id: Option<u64>,
+    /// The text written about the object in the entry.
+    #[serde(default)]
+    pub body: String,
+    /// Comments on the entry. Only included if requested in the query.
+    #[serde(default, rename = "comment")]
+    pub comments: Vec<GeekListComment>,
+}
+
+/// The type of object that a [GeekListItem] is for.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub enum GeekListObjectType {
+    /// A thing, such as a board game or expansion.
+    #[serde(rename = "thing")]
+    Thing,
+    /// A person, such as a designer or artist.
+    #[serde(rename = "person")]
+    Person,
+    /// A company, such as a publisher.
+    #[serde(rename = "company")]
+    Company,
+    /// A family of things.
+    #[serde(rename = "family")]
+    Family,
+    /// Any other type of object, such as a file or another geeklist.
+    #[serde(other)]
+    Other,
+}
+
+/// A comment on a [GeekList] or one of its items.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct GeekListComment {
+    /// The username of the user who left the comment.
+    pub username: String,
+    /// The date and time the comment was posted.
+    #[serde(rename = "postdate", with = "rfc2822_date_deserializer")]
+    pub post_date: DateTime<Utc>,
+    /// The date and time the comment was last edited.
+    #[serde(rename = "editdate", with = "rfc2822_date_deserializer")]
+    pub edit_date: DateTime<Utc>,
+    /// The number of thumbs up the comment has received.
+    pub thumbs: u64,
+    /// The text of the comment.
+    #[serde(default, rename = "$value")]
+    pub text: String,
+}
+
+/// Required query paramters.
+#[derive(Clone, Debug)]
+pub struct BaseGeekListQuery {
+    pub(crate) id: u64,
+}
+
+/// All optional query parameters for making a request to the
+/// geeklist endpoint.
+#[derive(Clone, Debug, Default)]
+pub struct GeekListQueryParams {
+    /// Include the comments on the geeklist and its items.
+    include_comments: Option<bool>,
+}
+
+impl GeekListQueryParams {
+    /// Constructs a new geeklist query with parameters set to None.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the include_comments field. If set to true then the comments on the
+    /// geeklist and each of its items will be included.
+    pub fn include_comments(mut self, include_comments: bool) -> Self {
+        self.include_comments = Some(include_comments);
+        self
+    }
+}
+
+/// Struct for building a query for the request to the geeklist endpoint.
+#[derive(Clone, Debug)]
+struct GeekListQueryBuilder {
+    params: GeekListQueryParams,
+}
+
+impl GeekListQueryBuilder {
+    /// Constructs a new query builder from the query parameters. The ID is not
+    /// included as it is part of the path on the legacy API.
+    fn new(params: GeekListQueryParams) -> Self {
+        Self { params }
+    }
+
+    pub fn build(self) -> Vec<(&'static str, String)> {
+        let mut query_params: Vec<_> = vec![];
+        match self.params.include_comments {
+            Some(true) => query_params.push(("comments", "1".to_string())),
+            Some(false) => query_params.push(("comments", "0".to_string())),
+            None => {}
+        }
+        query_params
+    }
+}
+
+/// GeekList endpoint of the API. Used for returning user created lists of items.
+///
+/// Geeklists are only available on the legacy version of the API, so requests
+/// are made against that rather than the current version.
+pub struct GeekListApi<'api> {
+    pub(crate) api: &'api BoardGameGeekApi,
+    endpoint: &'static str,
+}
+
+impl<'api> GeekListApi<'api> {
+    pub(crate) fn new(api: &'api BoardGameGeekApi) -> Self {
+        Self {
+            api,
+            endpoint: "geeklist",
+        }
+    }
+
+    /// Gets a geeklist by its ID, without any comments.
+    pub async fn get(&self, id: u64) -> Result<GeekList> {
+        self.get_from_query(id, GeekListQueryParams::new()).await
+    }
+
+    /// Makes a request for a geeklist from a [GeekListQueryParams].
+    pub async fn get_from_query(
+        &self,
+        id: u64,
+        query_params: GeekListQueryParams,
+    ) -> Result<GeekList> {
+        let base = BaseGeekListQuery { id };
+        let query = GeekListQueryBuilder::new(query_params);
+
+        let request = self
+            .api
+            .build_legacy_request(&format!("{}/{}", self.endpoint, base.id), &query.build());
+        self.api.execute_request::<GeekList>(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use mockito::Matcher;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn get_from_query() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .legacy_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/geeklist/331520")
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "comments".into(),
+                "1".into(),
+            )]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/geeklist/geeklist.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let geeklist = api
+            .geeklist()
+            .get_from_query(331520, GeekListQueryParams::new().include_comments(true))
+            .await;
+        mock.assert_async().await;
+
+        assert!(geeklist.is_ok(), "error returned when okay expected");
+        assert_eq!(
+            geeklist.unwrap(),
+            GeekList {
+                id: 331520,
+                title: "Games for our Friday night group".into(),
+                username: "bluebearbgg".into(),
+                post_date: Utc.with_ymd_and_hms(2024, 1, 9, 17, 30, 37).unwrap(),
+                edit_date: Utc.with_ymd_and_hms(2024, 4, 12, 8, 2, 11).unwrap(),
+                thumbs: 27,
+                number_of_items: 2,
+                description: "Everything we have been playing on Friday nights this year.".into(),
+                comments: vec![GeekListComment {
+                    username: "cardboardsam".into(),
+                    post_date: Utc.with_ymd_and_hms(2024, 1, 10, 9, 11, 12).unwrap(),
+                    edit_date: Utc.with_ymd_and_hms(2024, 1, 10, 9, 11, 12).unwrap(),
+                    thumbs: 2,
+                    text: "Great list, add Brass!".into(),
+                }],
+                items: vec![
+                    GeekListItem {
+                        id: 10634532,
+                        object_type: GeekListObjectType::Thing,
+                        subtype: "boardgame".into(),
+                        object_id: 312484,
+                        object_name: "Lost Ruins of Arnak".into(),
+                        username: "bluebearbgg".into(),
+                        post_date: Utc.with_ymd_and_hms(2024, 1, 9, 17, 35, 2).unwrap(),
+                        edit_date: Utc.with_ymd_and_hms(2024, 1, 9, 17, 35, 2).unwrap(),
+                        thumbs: 5,
+                        image_id: Some(6013226),
+                        body: "Our most played game, the expansion leaders make it even better."
+                            .into(),
+                        comments: vec![GeekListComment {
+                            username: "meeplequeen".into(),
+                            post_date: Utc.with_ymd_and_hms(2024, 1, 11, 20, 0, 0).unwrap(),
+                            edit_date: Utc.with_ymd_and_hms(2024, 1, 11, 20, 5, 0).unwrap(),
+                            thumbs: 1,
+                            text: "Agreed!".into(),
+                        }],
+                    },
+                    GeekListItem {
+                        id: 10634540,
+                        object_type: GeekListObjectType::Person,
+                        subtype: "boardgamedesigner".into(),
+                        object_id: 127823,
+                        object_name: "Elwen".into(),
+                        username: "bluebearbgg".into(),
+                        post_date: Utc.with_ymd_and_hms(2024, 1, 9, 17, 40, 44).unwrap(),
+                        edit_date: Utc.with_ymd_and_hms(2024, 1, 9, 17, 40, 44).unwrap(),
+                        thumbs: 0,
+                        image_id: None,
+                        body: "".into(),
+                        comments: vec![],
+                    },
+                ],
+            },
+        );
+    }
+}
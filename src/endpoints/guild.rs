@@ -0,0 +1,441 @@
+use chrono::{DateTime, Utc};
+use futures_util::Stream;
+use serde::Deserialize;
+
+use crate::utils::{deserialize_optional_string, rfc2822_date_deserializer, stream_pages};
+use crate::{BoardGameGeekApi, Error, Result};
+
+/// A guild on the site, a group of users such as a local board game club.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Guild {
+    /// The ID of the guild.
+    pub id: u64,
+    /// The name of the guild.
+    pub name: String,
+    /// The date and time the guild was created.
+    pub created: DateTime<Utc>,
+    /// The category of the guild, such as "group" or "interest".
+    pub category: String,
+    /// The guild's website, if set.
+    pub website: Option<String>,
+    /// The username of the user who manages the guild.
+    pub manager: String,
+    /// The description of the guild.
+    pub description: String,
+    /// Where the guild is based.
+    pub location: GuildLocation,
+    /// A page of the members of the guild. Only included if requested in the query.
+    pub members: Option<GuildMembers>,
+}
+
+/// The location of a guild. Any part of the address can be omitted.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct GuildLocation {
+    /// The first line of the address.
+    #[serde(
+        default,
+        rename = "addr1",
+        deserialize_with = "deserialize_optional_string"
+    )]
+    pub address_line_1: Option<String>,
+    /// The second line of the address.
+    #[serde(
+        default,
+        rename = "addr2",
+        deserialize_with = "deserialize_optional_string"
+    )]
+    pub address_line_2: Option<String>,
+    /// The city.
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
+    pub city: Option<String>,
+    /// The state or province.
+    #[serde(
+        default,
+        rename = "stateorprovince",
+        deserialize_with = "deserialize_optional_string"
+    )]
+    pub state_or_province: Option<String>,
+    /// The postal code.
+    #[serde(
+        default,
+        rename = "postalcode",
+        deserialize_with = "deserialize_optional_string"
+    )]
+    pub postal_code: Option<String>,
+    /// The country.
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
+    pub country: Option<String>,
+}
+
+/// A page of the members of a guild.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct GuildMembers {
+    /// The total number of members in the guild across all pages.
+    #[serde(rename = "count")]
+    pub total: u64,
+    /// The page number, starting from 1.
+    pub page: u64,
+    /// The members on this page.
+    #[serde(default, rename = "$value")]
+    pub members: Vec<GuildMember>,
+}
+
+/// A member of a guild.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct GuildMember {
+    /// The username of the member.
+    pub name: String,
+    /// The date and time the member joined the guild.
+    #[serde(rename = "date", with = "rfc2822_date_deserializer")]
+    pub join_date: DateTime<Utc>,
+}
+
+// Intermediary struct needed due to the way the XML is structured. The API returns
+// a guild with only the ID and an error tag when the guild is not found.
+#[derive(Debug, Deserialize)]
+struct XmlGuild {
+    id: u64,
+    name: Option<String>,
+    created: Option<String>,
+    category: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
+    website: Option<String>,
+    manager: Option<String>,
+    description: Option<String>,
+    location: Option<GuildLocation>,
+    members: Option<GuildMembers>,
+    error: Option<String>,
+}
+
+impl TryFrom<XmlGuild> for Guild {
+    type Error = Error;
+
+    fn try_from(guild: XmlGuild) -> Result<Self> {
+        if guild.error.is_some() {
+            return Err(Error::ItemNotFoundError(guild.id));
+        }
+        let missing = |field: &str| Error::InvalidResponseError(format!("missing field `{field}`"));
+
+        let created = guild.created.ok_or_else(|| missing("created"))?;
+        let created = rfc2822_date_deserializer::parse(&created).map_err(|e| {
+            Error::InvalidResponseError(format!("failed to parse created date: {e}"))
+        })?;
+
+        Ok(Self {
+            id: guild.id,
+            name: guild.name.ok_or_else(|| missing("name"))?,
+            created,
+            category: guild.category.ok_or_else(|| missing("category"))?,
+            website: guild.website,
+            manager: guild.manager.ok_or_else(|| missing("manager"))?,
+            description: guild.description.ok_or_else(|| missing("description"))?,
+            location: guild.location.ok_or_else(|| missing("location"))?,
+            members: guild.members,
+        })
+    }
+}
+
+/// The order to return the members of a guild in.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GuildMemberSort {
+    /// Sort alphabetically by username.
+    Username,
+    /// Sort by the date the member joined the guild.
+    JoinDate,
+}
+
+/// Required query paramters.
+#[derive(Clone, Debug)]
+pub struct BaseGuildQuery {
+    pub(crate) id: u64,
+}
+
+/// All optional query parameters for making a request to the
+/// guild endpoint.
+#[derive(Clone, Debug, Default)]
+pub struct GuildQueryParams {
+    /// Include a page of the members of the guild.
+    include_members: Option<bool>,
+    /// The order to return the members in, by username if not set.
+    sort: Option<GuildMemberSort>,
+    /// The page of members to return, starting from 1.
+    page: Option<u64>,
+}
+
+impl GuildQueryParams {
+    /// Constructs a new guild query with parameters set to None.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the include_members field. If set to true then a page of the members of
+    /// the guild will be included.
+    pub fn include_members(mut self, include_members: bool) -> Self {
+        self.include_members = Some(include_members);
+        self
+    }
+
+    /// Sets the sort field, for which order the members are returned in.
+    pub fn sort(mut self, sort: GuildMemberSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the page field, for which page of members to return. Pages start from 1.
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
+/// Struct for building a query for the request to the guild endpoint.
+#[derive(Clone, Debug)]
+struct GuildQueryBuilder {
+    base: BaseGuildQuery,
+    params: GuildQueryParams,
+}
+
+impl GuildQueryBuilder {
+    /// Constructs a new query builder from a base query, and the rest of the parameters.
+    fn new(base: BaseGuildQuery, params: GuildQueryParams) -> Self {
+        Self { base, params }
+    }
+
+    pub fn build(self) -> Vec<(&'static str, String)> {
+        let mut query_params: Vec<_> = vec![];
+        query_params.push(("id", self.base.id.to_string()));
+
+        match self.params.include_members {
+            Some(true) => query_params.push(("members", "1".to_string())),
+            Some(false) => query_params.push(("members", "0".to_string())),
+            None => {}
+        }
+        match self.params.sort {
+            Some(GuildMemberSort::Username) => query_params.push(("sort", "username".to_string())),
+            Some(GuildMemberSort::JoinDate) => query_params.push(("sort", "date".to_string())),
+            None => {}
+        }
+        if let Some(page) = self.params.page {
+            query_params.push(("page", page.to_string()));
+        }
+        query_params
+    }
+}
+
+/// Guild endpoint of the API. Used for returning the details of a guild, along
+/// with its members.
+pub struct GuildApi<'api> {
+    pub(crate) api: &'api BoardGameGeekApi,
+    endpoint: &'static str,
+}
+
+impl<'api> GuildApi<'api> {
+    // The number of members the API returns per page.
+    const PAGE_SIZE: u64 = 25;
+
+    pub(crate) fn new(api: &'api BoardGameGeekApi) -> Self {
+        Self {
+            api,
+            endpoint: "guild",
+        }
+    }
+
+    /// Gets a guild by its ID, without its members.
+    pub async fn get(&self, id: u64) -> Result<Guild> {
+        self.get_from_query(id, GuildQueryParams::new()).await
+    }
+
+    /// Makes a request for a guild from a [GuildQueryParams]. Returns
+    /// [Error::ItemNotFoundError] if no guild with the ID exists.
+    pub async fn get_from_query(&self, id: u64, query_params: GuildQueryParams) -> Result<Guild> {
+        let query = GuildQueryBuilder::new(BaseGuildQuery { id }, query_params);
+
+        let request = self.api.build_request(self.endpoint, &query.build());
+        let guild = self.api.execute_request::<XmlGuild>(request).await?;
+        Guild::try_from(guild)
+    }
+
+    /// Gets all of the members of a guild, in the order set in the query.
+    ///
+    /// The members are returned as a stream which requests each page in turn as it is
+    /// needed, starting from the page in the query, and ends once the total number of
+    /// members reported by the API have been returned. If a request fails the error is
+    /// returned and the stream ends.
+    pub fn members(
+        &self,
+        id: u64,
+        query_params: GuildQueryParams,
+    ) -> impl Stream<Item = Result<GuildMember>> + 'api {
+        let api = self.api;
+        let first_page = query_params.page.unwrap_or(1);
+        let query_params = query_params.include_members(true);
+
+        stream_pages(first_page, Self::PAGE_SIZE, move |page| {
+            let query_params = query_params.clone();
+            async move {
+                let guild = GuildApi::new(api)
+                    .get_from_query(id, query_params.page(page))
+                    .await?;
+                Ok(guild
+                    .members
+                    .map(|members| (members.members, members.total))
+                    .unwrap_or_default())
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use futures_util::StreamExt;
+    use mockito::Matcher;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn get() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/guild")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".into(), "3422".into()),
+                Matcher::UrlEncoded("members".into(), "1".into()),
+                Matcher::UrlEncoded("sort".into(), "date".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/guild/guild.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let query = GuildQueryParams::new()
+            .include_members(true)
+            .sort(GuildMemberSort::JoinDate);
+        let guild = api.guild().get_from_query(3422, query).await;
+        mock.assert_async().await;
+
+        assert!(guild.is_ok(), "error returned when okay expected");
+        assert_eq!(
+            guild.unwrap(),
+            Guild {
+                id: 3422,
+                name: "Bristol Board Gamers".into(),
+                created: Utc.with_ymd_and_hms(2009, 3, 14, 18, 25, 41).unwrap(),
+                category: "group".into(),
+                website: Some("https://bristolboardgamers.example.com".into()),
+                manager: "cardboardsam".into(),
+                description: "A weekly board game night in Bristol. All welcome, from first timers to heavy euro fans.".into(),
+                location: GuildLocation {
+                    address_line_1: Some("The Meeple Arms".into()),
+                    address_line_2: None,
+                    city: Some("Bristol".into()),
+                    state_or_province: None,
+                    postal_code: Some("BS1 4DJ".into()),
+                    country: Some("United Kingdom".into()),
+                },
+                members: Some(GuildMembers {
+                    total: 26,
+                    page: 1,
+                    members: vec![
+                        GuildMember {
+                            name: "cardboardsam".into(),
+                            join_date: Utc.with_ymd_and_hms(2009, 3, 14, 18, 25, 41).unwrap(),
+                        },
+                        GuildMember {
+                            name: "bluebearbgg".into(),
+                            join_date: Utc.with_ymd_and_hms(2019, 7, 2, 9, 12, 3).unwrap(),
+                        },
+                    ],
+                }),
+            },
+        );
+    }
+
+    #[tokio::test]
+    async fn get_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/guild")
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "id".into(),
+                "999999".into(),
+            )]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/guild/guild_not_found.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let guild = api.guild().get(999999).await;
+        mock.assert_async().await;
+
+        assert!(matches!(guild, Err(Error::ItemNotFoundError(999999))));
+    }
+
+    #[tokio::test]
+    async fn members() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let first_mock = server
+            .mock("GET", "/guild")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".into(), "3422".into()),
+                Matcher::UrlEncoded("members".into(), "1".into()),
+                Matcher::UrlEncoded("page".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/guild/guild.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+        let second_mock = server
+            .mock("GET", "/guild")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".into(), "3422".into()),
+                Matcher::UrlEncoded("members".into(), "1".into()),
+                Matcher::UrlEncoded("page".into(), "2".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/guild/guild_members_page_2.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let members: Vec<Result<GuildMember>> = api
+            .guild()
+            .members(3422, GuildQueryParams::new())
+            .collect()
+            .await;
+        first_mock.assert_async().await;
+        second_mock.assert_async().await;
+
+        let names: Vec<String> = members
+            .into_iter()
+            .map(|member| member.unwrap().name)
+            .collect();
+        assert_eq!(names, vec!["cardboardsam", "bluebearbgg", "meeplequeen"]);
+    }
+}
@@ -2,29 +2,106 @@ use serde::Deserialize;
 
 use crate::{BoardGameGeekApi, Result};
 
-/// The returned struct containing a list of hot board games.
+/// The returned struct containing a list of hot items.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
 pub struct HotList {
-    /// The list of hot board games.
+    /// The list of hot items.
     #[serde(rename = "$value")]
     pub items: Vec<HotItem>,
 }
 
+/// An item on the hot list. This could be a game, a person or a company depending on
+/// the type of hot list requested.
 #[derive(Clone, Debug, PartialEq)]
 pub struct HotItem {
-    /// The ID of the game.
+    /// The ID of the item.
     pub id: u64,
     /// The rank within the hotlist, should be ordered from 1 to 50.
     pub rank: u64,
-    /// A link to a jpg thumbnail image for the game.
+    /// A link to a jpg thumbnail image for the item.
     pub thumbnail: String,
-    /// The name of the game.
+    /// The name of the item.
     pub name: String,
-    /// The year the game was first published.
-    pub year_published: i64,
+    /// The year the game was first published. Omitted for people and companies.
+    pub year_published: Option<i64>,
 }
 
-/// Hot list endpoint of the API. Used for returning the current trending board games.
+/// The type of items to include in a hot list.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HotListType {
+    /// Board games, the default if no type is set.
+    BoardGame,
+    /// Role playing games.
+    Rpg,
+    /// Video games.
+    VideoGame,
+    /// People involved in board games, such as designers and artists.
+    BoardGamePerson,
+    /// People involved in role playing games.
+    RpgPerson,
+    /// Board game companies, such as publishers.
+    BoardGameCompany,
+    /// Role playing game companies.
+    RpgCompany,
+    /// Video game companies.
+    VideoGameCompany,
+}
+
+/// All optional query parameters for making a request to the
+/// hot list endpoint.
+#[derive(Clone, Debug, Default)]
+pub struct HotListQueryParams {
+    /// The type of items to include in the hot list, board games if not set.
+    hot_list_type: Option<HotListType>,
+}
+
+impl HotListQueryParams {
+    /// Constructs a new hot list query with parameters set to None.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the hot_list_type field, so that the hot list will be of that type of item.
+    pub fn hot_list_type(mut self, hot_list_type: HotListType) -> Self {
+        self.hot_list_type = Some(hot_list_type);
+        self
+    }
+}
+
+/// Struct for building a query for the request to the hot list endpoint.
+#[derive(Clone, Debug)]
+struct HotListQueryBuilder {
+    params: HotListQueryParams,
+}
+
+impl HotListQueryBuilder {
+    /// Constructs a new query builder from the query parameters.
+    fn new(params: HotListQueryParams) -> Self {
+        Self { params }
+    }
+
+    pub fn build(self) -> Vec<(&'static str, String)> {
+        let mut query_params: Vec<_> = vec![];
+        let hot_list_type = match self.params.hot_list_type {
+            Some(HotListType::BoardGame) => Some("boardgame"),
+            Some(HotListType::Rpg) => Some("rpg"),
+            Some(HotListType::VideoGame) => Some("videogame"),
+            Some(HotListType::BoardGamePerson) => Some("boardgameperson"),
+            Some(HotListType::RpgPerson) => Some("rpgperson"),
+            Some(HotListType::BoardGameCompany) => Some("boardgamecompany"),
+            Some(HotListType::RpgCompany) => Some("rpgcompany"),
+            Some(HotListType::VideoGameCompany) => Some("videogamecompany"),
+            None => None,
+        };
+        if let Some(hot_list_type) = hot_list_type {
+            query_params.push(("type", hot_list_type.to_string()));
+        }
+        query_params
+    }
+}
+
+/// Hot list endpoint of the API. Used for returning the current trending board games,
+/// or other types of item such as role playing games or board game designers.
 pub struct HotListApi<'api> {
     pub(crate) api: &'api BoardGameGeekApi,
     endpoint: &'static str,
@@ -38,9 +115,22 @@ impl<'api> HotListApi<'api> {
         }
     }
 
-    /// Gets the hot list.
+    /// Gets the hot list of board games.
     pub async fn get(&self) -> Result<HotList> {
-        let request = self.api.build_request(self.endpoint, &[]);
+        self.get_from_query(HotListQueryParams::new()).await
+    }
+
+    /// Gets the hot list for a type of item.
+    pub async fn get_by_type(&self, hot_list_type: HotListType) -> Result<HotList> {
+        self.get_from_query(HotListQueryParams::new().hot_list_type(hot_list_type))
+            .await
+    }
+
+    /// Makes a request for the hot list from a [HotListQueryParams].
+    pub async fn get_from_query(&self, query_params: HotListQueryParams) -> Result<HotList> {
+        let query = HotListQueryBuilder::new(query_params);
+
+        let request = self.api.build_request(self.endpoint, &query.build());
         self.api.execute_request(request).await
     }
 }
@@ -54,10 +144,10 @@ mod tests {
     #[tokio::test]
     async fn get() {
         let mut server = mockito::Server::new_async().await;
-        let api = BoardGameGeekApi {
-            base_url: server.url(),
-            client: reqwest::Client::new(),
-        };
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
 
         let mock = server
             .mock("GET", "/hot")
@@ -84,7 +174,51 @@ mod tests {
                 rank: 1,
                 thumbnail: "https://cf.geekdo-images.com/XWImAu_3RK61wbzcKboVdA__thumb/img/Ry-6KHwNgERWadyxs1X1_P3dMvY=/fit-in/200x150/filters:strip_icc()/pic8145530.png".into(),
                 name: "Arcs".into(),
-                year_published: 2024,
+                year_published: Some(2024),
+            }
+        )
+    }
+
+    #[tokio::test]
+    async fn get_by_type() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/hot")
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "type".into(),
+                "boardgameperson".into(),
+            )]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/hot_list/hot_list_board_game_person.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let hot_list = api
+            .hot_list()
+            .get_by_type(HotListType::BoardGamePerson)
+            .await;
+        mock.assert_async().await;
+
+        assert!(hot_list.is_ok(), "error returned when okay expected");
+        let hot_list = hot_list.unwrap();
+
+        assert_eq!(hot_list.items.len(), 2);
+        assert_eq!(
+            hot_list.items[1],
+            HotItem {
+                id: 127822,
+                rank: 2,
+                thumbnail: "https://cf.geekdo-images.com/Ojv3z5frtdbq2_ciGAT9pA__thumb/img/sNd3-6qDxRlOYIsIbnUpmXL_7Wc=/fit-in/200x150/filters:strip_icc()/pic5613473.jpg".into(),
+                name: "Mín".into(),
+                year_published: None,
             }
         )
     }
@@ -8,8 +8,41 @@
 pub(crate) mod collection;
 pub use collection::*;
 
+pub(crate) mod collection_editing;
+pub use collection_editing::*;
+
+pub(crate) mod family;
+pub use family::*;
+
+pub(crate) mod forum;
+pub use forum::*;
+
+pub(crate) mod forum_list;
+pub use forum_list::*;
+
+pub(crate) mod geeklist;
+pub use geeklist::*;
+
+pub(crate) mod guild;
+pub use guild::*;
+
 pub(crate) mod hot_list;
 pub use hot_list::*;
 
+pub(crate) mod play_logging;
+pub use play_logging::*;
+
+pub(crate) mod plays;
+pub use plays::*;
+
 pub(crate) mod search;
 pub use search::*;
+
+pub(crate) mod thing;
+pub use thing::*;
+
+pub(crate) mod thread;
+pub use thread::*;
+
+pub(crate) mod user;
+pub use user::*;
@@ -0,0 +1,481 @@
+use core::fmt;
+
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::{BoardGameGeekApi, Error, PlayPlayer, Result};
+
+/// The ID of a play logged on the site.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlayId(pub u64);
+
+impl fmt::Display for PlayId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The details of a play of a game, to be logged or to replace those of an
+/// existing play.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlayDetails {
+    /// The ID of the game that was played.
+    item_id: u64,
+    /// The date the game was played.
+    date: NaiveDate,
+    /// The number of times the game was played.
+    quantity: u64,
+    /// How long the game took to play.
+    length: Option<Duration>,
+    /// Whether the game was not played to completion.
+    incomplete: bool,
+    /// Whether the play should be excluded from win statistics.
+    no_win_stats: bool,
+    /// Where the game was played.
+    location: Option<String>,
+    /// Any comments on the play.
+    comments: Option<String>,
+    /// The players in the game.
+    players: Vec<PlayPlayer>,
+}
+
+impl PlayDetails {
+    /// Constructs the details of a single play of a game on the given date, with
+    /// everything else left unset.
+    pub fn new(item_id: u64, date: NaiveDate) -> Self {
+        Self {
+            item_id,
+            date,
+            quantity: 1,
+            length: None,
+            incomplete: false,
+            no_win_stats: false,
+            location: None,
+            comments: None,
+            players: vec![],
+        }
+    }
+
+    /// Sets the quantity field, for the number of times the game was played.
+    pub fn quantity(mut self, quantity: u64) -> Self {
+        self.quantity = quantity;
+        self
+    }
+
+    /// Sets the length field, for how long the game took to play. This is recorded
+    /// to the nearest minute.
+    pub fn length(mut self, length: Duration) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Sets the incomplete field, for whether the game was not played to completion.
+    pub fn incomplete(mut self, incomplete: bool) -> Self {
+        self.incomplete = incomplete;
+        self
+    }
+
+    /// Sets the no_win_stats field, for whether the play should be excluded from
+    /// win statistics.
+    pub fn no_win_stats(mut self, no_win_stats: bool) -> Self {
+        self.no_win_stats = no_win_stats;
+        self
+    }
+
+    /// Sets the location field, for where the game was played.
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    /// Sets the comments field, for any comments on the play.
+    pub fn comments(mut self, comments: impl Into<String>) -> Self {
+        self.comments = Some(comments.into());
+        self
+    }
+
+    /// Adds a player to the play, along with their score and whether they won.
+    pub fn player(mut self, player: PlayPlayer) -> Self {
+        self.players.push(player);
+        self
+    }
+}
+
+// The body of a request to save a play, in the format the site expects.
+#[derive(Serialize)]
+struct SavePlayRequest<'a> {
+    action: &'static str,
+    ajax: u8,
+    #[serde(rename = "playid", skip_serializing_if = "Option::is_none")]
+    play_id: Option<u64>,
+    #[serde(rename = "objecttype")]
+    object_type: &'static str,
+    #[serde(rename = "objectid")]
+    object_id: u64,
+    #[serde(rename = "playdate")]
+    date: String,
+    quantity: u64,
+    length: i64,
+    incomplete: bool,
+    #[serde(rename = "nowinstats")]
+    no_win_stats: bool,
+    location: &'a str,
+    comments: &'a str,
+    players: Vec<SavePlayPlayer<'a>>,
+}
+
+impl<'a> SavePlayRequest<'a> {
+    fn new(play_id: Option<PlayId>, play: &'a PlayDetails) -> Self {
+        Self {
+            action: "save",
+            ajax: 1,
+            play_id: play_id.map(|play_id| play_id.0),
+            object_type: "thing",
+            object_id: play.item_id,
+            date: play.date.format("%Y-%m-%d").to_string(),
+            quantity: play.quantity,
+            length: play.length.map_or(0, |length| length.num_minutes()),
+            incomplete: play.incomplete,
+            no_win_stats: play.no_win_stats,
+            location: play.location.as_deref().unwrap_or_default(),
+            comments: play.comments.as_deref().unwrap_or_default(),
+            players: play.players.iter().map(SavePlayPlayer::from).collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SavePlayPlayer<'a> {
+    username: &'a str,
+    #[serde(rename = "userid", skip_serializing_if = "Option::is_none")]
+    user_id: Option<u64>,
+    name: &'a str,
+    position: &'a str,
+    color: &'a str,
+    score: &'a str,
+    rating: String,
+    new: bool,
+    win: bool,
+}
+
+impl<'a> From<&'a PlayPlayer> for SavePlayPlayer<'a> {
+    fn from(player: &'a PlayPlayer) -> Self {
+        Self {
+            username: player.username.as_deref().unwrap_or_default(),
+            user_id: player.user_id,
+            name: &player.name,
+            position: player.start_position.as_deref().unwrap_or_default(),
+            color: player.color.as_deref().unwrap_or_default(),
+            score: player.score.as_deref().unwrap_or_default(),
+            rating: player
+                .rating
+                .map(|rating| rating.to_string())
+                .unwrap_or_default(),
+            new: player.new,
+            win: player.win,
+        }
+    }
+}
+
+// The response from saving a play, which contains either the ID of the play or
+// an error message.
+#[derive(Deserialize)]
+struct SavePlayResponse {
+    #[serde(default, rename = "playid")]
+    play_id: Option<JsonId>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+// The response from deleting a play, which contains an error message if it failed.
+#[derive(Deserialize)]
+struct DeletePlayResponse {
+    #[serde(default)]
+    error: Option<String>,
+}
+
+// The site returns IDs as either strings or numbers.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonId {
+    Number(u64),
+    String(String),
+}
+
+impl TryFrom<SavePlayResponse> for PlayId {
+    type Error = Error;
+
+    fn try_from(response: SavePlayResponse) -> Result<Self> {
+        if let Some(error) = response.error {
+            return Err(Error::UnknownApiErrors(vec![error]));
+        }
+        let play_id = match response.play_id {
+            Some(JsonId::Number(play_id)) => Some(play_id),
+            Some(JsonId::String(play_id)) => play_id.parse::<u64>().ok(),
+            None => None,
+        };
+        play_id.map(PlayId).ok_or_else(|| {
+            Error::InvalidResponseError("no play ID returned from saving play".to_string())
+        })
+    }
+}
+
+/// Play logging endpoint of the site. Used for logging, editing and deleting the
+/// plays of the logged in user. All requests require a user to be logged in with
+/// [BoardGameGeekApi::login].
+pub struct PlayLoggingApi<'api> {
+    pub(crate) api: &'api BoardGameGeekApi,
+    endpoint: &'static str,
+}
+
+impl<'api> PlayLoggingApi<'api> {
+    pub(crate) fn new(api: &'api BoardGameGeekApi) -> Self {
+        Self {
+            api,
+            endpoint: "geekplay.php",
+        }
+    }
+
+    /// Logs a new play for the logged in user, returning the ID of the new play.
+    pub async fn create(&self, play: &PlayDetails) -> Result<PlayId> {
+        self.save(SavePlayRequest::new(None, play)).await
+    }
+
+    /// Replaces the details of an existing play logged by the logged in user.
+    pub async fn edit(&self, play_id: PlayId, play: &PlayDetails) -> Result<PlayId> {
+        self.save(SavePlayRequest::new(Some(play_id), play)).await
+    }
+
+    /// Deletes a play logged by the logged in user.
+    pub async fn delete(&self, play_id: PlayId) -> Result<()> {
+        let request = self.api.build_site_request(self.endpoint)?.form(&[
+            ("ajax", "1".to_string()),
+            ("action", "delete".to_string()),
+            ("playid", play_id.to_string()),
+            ("finalize", "1".to_string()),
+        ]);
+        let response = self.api.send_request(request).await?;
+        let response: DeletePlayResponse = response.json().await?;
+        if let Some(error) = response.error {
+            return Err(Error::UnknownApiErrors(vec![error]));
+        }
+        self.invalidate_cached_plays();
+        Ok(())
+    }
+
+    async fn save(&self, body: SavePlayRequest<'_>) -> Result<PlayId> {
+        let request = self.api.build_site_request(self.endpoint)?.json(&body);
+        let response = self.api.send_request(request).await?;
+        let response: SavePlayResponse = response.json().await?;
+        self.invalidate_cached_plays();
+        PlayId::try_from(response)
+    }
+
+    // Removes any cached plays, as they will no longer include the changed play.
+    fn invalidate_cached_plays(&self) {
+        if let Some(cache) = self.api.cache() {
+            cache.invalidate_endpoint("plays");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use super::*;
+    use crate::session::logged_in_api;
+
+    #[tokio::test]
+    async fn create() {
+        let mut server = mockito::Server::new_async().await;
+        let api = logged_in_api(&server);
+
+        let mock = server
+            .mock("POST", "/geekplay.php")
+            .match_header("cookie", "SessionID=xyz789")
+            .match_body(Matcher::JsonString(
+                r#"{
+                    "action": "save",
+                    "ajax": 1,
+                    "objecttype": "thing",
+                    "objectid": 312484,
+                    "playdate": "2024-04-13",
+                    "quantity": 1,
+                    "length": 75,
+                    "incomplete": false,
+                    "nowinstats": false,
+                    "location": "Home",
+                    "comments": "Close game.",
+                    "players": [
+                        {
+                            "username": "somename",
+                            "name": "Some Name",
+                            "position": "1",
+                            "color": "Blue",
+                            "score": "52",
+                            "rating": "8",
+                            "new": false,
+                            "win": true
+                        },
+                        {
+                            "username": "",
+                            "name": "Friend",
+                            "position": "",
+                            "color": "",
+                            "score": "48",
+                            "rating": "",
+                            "new": true,
+                            "win": false
+                        }
+                    ]
+                }"#
+                .into(),
+            ))
+            .with_status(200)
+            .with_body(r#"{"playid":"84123456","numplays":3,"html":""}"#)
+            .create_async()
+            .await;
+
+        let play = PlayDetails::new(312484, NaiveDate::from_ymd_opt(2024, 4, 13).unwrap())
+            .length(Duration::minutes(75))
+            .location("Home")
+            .comments("Close game.")
+            .player(PlayPlayer {
+                username: Some("somename".into()),
+                user_id: None,
+                name: "Some Name".into(),
+                start_position: Some("1".into()),
+                color: Some("Blue".into()),
+                score: Some("52".into()),
+                new: false,
+                rating: Some(8.0),
+                win: true,
+            })
+            .player(PlayPlayer {
+                username: None,
+                user_id: None,
+                name: "Friend".into(),
+                start_position: None,
+                color: None,
+                score: Some("48".into()),
+                new: true,
+                rating: None,
+                win: false,
+            });
+        let play_id = api.play_logging().create(&play).await;
+        mock.assert_async().await;
+
+        assert!(play_id.is_ok(), "error returned when okay expected");
+        assert_eq!(play_id.unwrap(), PlayId(84123456));
+    }
+
+    #[tokio::test]
+    async fn edit() {
+        let mut server = mockito::Server::new_async().await;
+        let api = logged_in_api(&server);
+
+        let mock = server
+            .mock("POST", "/geekplay.php")
+            .match_body(Matcher::PartialJsonString(
+                r#"{"action": "save", "playid": 84123456, "quantity": 2}"#.into(),
+            ))
+            .with_status(200)
+            .with_body(r#"{"playid":84123456,"numplays":4,"html":""}"#)
+            .create_async()
+            .await;
+
+        let play =
+            PlayDetails::new(312484, NaiveDate::from_ymd_opt(2024, 4, 13).unwrap()).quantity(2);
+        let play_id = api.play_logging().edit(PlayId(84123456), &play).await;
+        mock.assert_async().await;
+
+        assert!(play_id.is_ok(), "error returned when okay expected");
+        assert_eq!(play_id.unwrap(), PlayId(84123456));
+    }
+
+    #[tokio::test]
+    async fn create_error() {
+        let mut server = mockito::Server::new_async().await;
+        let api = logged_in_api(&server);
+
+        let mock = server
+            .mock("POST", "/geekplay.php")
+            .with_status(200)
+            .with_body(r#"{"error":"Invalid item. Play not saved."}"#)
+            .create_async()
+            .await;
+
+        let play = PlayDetails::new(0, NaiveDate::from_ymd_opt(2024, 4, 13).unwrap());
+        let play_id = api.play_logging().create(&play).await;
+        mock.assert_async().await;
+
+        match play_id {
+            Err(Error::UnknownApiErrors(messages)) => {
+                assert_eq!(messages, vec!["Invalid item. Play not saved."]);
+            }
+            _ => panic!("unknown API error expected"),
+        }
+    }
+
+    #[tokio::test]
+    async fn delete() {
+        let mut server = mockito::Server::new_async().await;
+        let api = logged_in_api(&server);
+
+        let mock = server
+            .mock("POST", "/geekplay.php")
+            .match_header("cookie", "SessionID=xyz789")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("ajax".into(), "1".into()),
+                Matcher::UrlEncoded("action".into(), "delete".into()),
+                Matcher::UrlEncoded("playid".into(), "84123456".into()),
+                Matcher::UrlEncoded("finalize".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_body(r#"{"numplays":2,"html":""}"#)
+            .create_async()
+            .await;
+
+        let res = api.play_logging().delete(PlayId(84123456)).await;
+        mock.assert_async().await;
+
+        assert!(res.is_ok(), "error returned when okay expected");
+    }
+
+    #[tokio::test]
+    async fn delete_error() {
+        let mut server = mockito::Server::new_async().await;
+        let api = logged_in_api(&server);
+
+        let mock = server
+            .mock("POST", "/geekplay.php")
+            .with_status(200)
+            .with_body(r#"{"error":"You are not allowed to delete this play."}"#)
+            .create_async()
+            .await;
+
+        let res = api.play_logging().delete(PlayId(84123456)).await;
+        mock.assert_async().await;
+
+        match res {
+            Err(Error::UnknownApiErrors(messages)) => {
+                assert_eq!(messages, vec!["You are not allowed to delete this play."]);
+            }
+            _ => panic!("unknown API error expected"),
+        }
+    }
+
+    #[tokio::test]
+    async fn delete_not_logged_in() {
+        let server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            session: None,
+            ..logged_in_api(&server)
+        };
+
+        let res = api.play_logging().delete(PlayId(84123456)).await;
+
+        assert!(matches!(res, Err(Error::NotLoggedInError)));
+    }
+}
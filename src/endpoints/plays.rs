@@ -0,0 +1,525 @@
+use chrono::{Duration, NaiveDate};
+use futures_util::Stream;
+use serde::Deserialize;
+
+use super::ThingType;
+use crate::utils::{
+    deserialize_1_0_bool, deserialize_minutes, deserialize_optional_id,
+    deserialize_optional_nonzero_float, deserialize_optional_string, naive_date_deserializer,
+    stream_pages,
+};
+use crate::{BoardGameGeekApi, Result};
+
+/// A page of plays logged on the site, either by a user or of a particular item.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Plays {
+    /// The username whose plays these are. Only included when requesting plays by username.
+    #[serde(default)]
+    pub username: Option<String>,
+    /// The ID of the user whose plays these are. Only included when requesting plays
+    /// by username.
+    #[serde(default, rename = "userid")]
+    pub user_id: Option<u64>,
+    /// The total number of plays across all pages.
+    pub total: u64,
+    /// The page number, starting from 1.
+    pub page: u64,
+    /// The plays on this page, from most to least recent.
+    #[serde(default, rename = "$value")]
+    pub plays: Vec<Play>,
+}
+
+/// A single logged play of a game.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Play {
+    /// The ID of the play.
+    pub id: u64,
+    /// The date the game was played.
+    #[serde(with = "naive_date_deserializer")]
+    pub date: NaiveDate,
+    /// The number of times the game was played.
+    pub quantity: u64,
+    /// How long the game took to play, zero if not recorded.
+    #[serde(deserialize_with = "deserialize_minutes")]
+    pub length: Duration,
+    /// Whether the game was not played to completion.
+    #[serde(deserialize_with = "deserialize_1_0_bool")]
+    pub incomplete: bool,
+    /// Whether the play is excluded from win statistics.
+    #[serde(rename = "nowinstats", deserialize_with = "deserialize_1_0_bool")]
+    pub no_win_stats: bool,
+    /// Where the game was played, if recorded.
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
+    pub location: Option<String>,
+    /// The game that was played.
+    pub item: PlayItem,
+    /// Any comments left on the play.
+    #[serde(default)]
+    pub comments: Option<String>,
+    /// The players in the game, if recorded.
+    #[serde(default, deserialize_with = "deserialize_players")]
+    pub players: Vec<PlayPlayer>,
+}
+
+/// The game that was played in a [Play].
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct PlayItem {
+    /// The ID of the game.
+    #[serde(rename = "objectid")]
+    pub id: u64,
+    /// The name of the game.
+    pub name: String,
+}
+
+/// A player in a [Play], along with how they did.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct PlayPlayer {
+    /// The username of the player, if they are a user on the site.
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
+    pub username: Option<String>,
+    /// The ID of the player, if they are a user on the site.
+    #[serde(
+        default,
+        rename = "userid",
+        deserialize_with = "deserialize_optional_id"
+    )]
+    pub user_id: Option<u64>,
+    /// The name of the player.
+    pub name: String,
+    /// The starting position of the player, if recorded.
+    #[serde(
+        default,
+        rename = "startposition",
+        deserialize_with = "deserialize_optional_string"
+    )]
+    pub start_position: Option<String>,
+    /// The colour or team the player played as, if recorded.
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
+    pub color: Option<String>,
+    /// The player's score, if recorded. This is free text so is not necessarily a number.
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
+    pub score: Option<String>,
+    /// Whether this was the first time the player played the game.
+    #[serde(deserialize_with = "deserialize_1_0_bool")]
+    pub new: bool,
+    /// The 0-10 rating the player gave the game, if they gave one.
+    #[serde(default, deserialize_with = "deserialize_optional_nonzero_float")]
+    pub rating: Option<f64>,
+    /// Whether the player won.
+    #[serde(deserialize_with = "deserialize_1_0_bool")]
+    pub win: bool,
+}
+
+// Intermediary struct needed due to the way the XML is structured.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+struct Players {
+    #[serde(default, rename = "$value")]
+    players: Vec<PlayPlayer>,
+}
+
+fn deserialize_players<'de, D>(deserializer: D) -> core::result::Result<Vec<PlayPlayer>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let players: Players = Deserialize::deserialize(deserializer)?;
+    Ok(players.players)
+}
+
+/// Required query paramters. Plays can be requested either for a user, or for an item,
+/// or for a user but only of a particular item.
+#[derive(Clone, Debug)]
+pub struct BasePlaysQuery<'q> {
+    pub(crate) username: Option<&'q str>,
+    pub(crate) item_id: Option<u64>,
+}
+
+/// All optional query parameters for making a request to the
+/// plays endpoint.
+#[derive(Clone, Debug, Default)]
+pub struct PlaysQueryParams {
+    /// Only include plays of this item. Only used when requesting plays by username.
+    item_id: Option<u64>,
+    /// Only include plays on or after this date.
+    min_date: Option<NaiveDate>,
+    /// Only include plays on or before this date.
+    max_date: Option<NaiveDate>,
+    /// Only include plays of this type of item.
+    subtype: Option<ThingType>,
+    /// The page of plays to return, starting from 1.
+    page: Option<u64>,
+}
+
+impl PlaysQueryParams {
+    /// Constructs a new plays query with parameters set to None.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the item_id field, so that only plays of that item are returned.
+    /// Only used when requesting plays by username.
+    pub fn item_id(mut self, item_id: u64) -> Self {
+        self.item_id = Some(item_id);
+        self
+    }
+
+    /// Sets the min_date field, so that only plays on or after that date are returned.
+    pub fn min_date(mut self, min_date: NaiveDate) -> Self {
+        self.min_date = Some(min_date);
+        self
+    }
+
+    /// Sets the max_date field, so that only plays on or before that date are returned.
+    pub fn max_date(mut self, max_date: NaiveDate) -> Self {
+        self.max_date = Some(max_date);
+        self
+    }
+
+    /// Sets the subtype field, so that only plays of that type of item are returned.
+    pub fn subtype(mut self, subtype: ThingType) -> Self {
+        self.subtype = Some(subtype);
+        self
+    }
+
+    /// Sets the page field, for which page of plays to return. Pages start from 1.
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
+/// Struct for building a query for the request to the plays endpoint.
+#[derive(Clone, Debug)]
+struct PlaysQueryBuilder<'q> {
+    base: BasePlaysQuery<'q>,
+    params: PlaysQueryParams,
+}
+
+impl<'builder> PlaysQueryBuilder<'builder> {
+    /// Constructs a new query builder from a base query, and the rest of the parameters.
+    fn new(base: BasePlaysQuery<'builder>, params: PlaysQueryParams) -> Self {
+        Self { base, params }
+    }
+
+    pub fn build(self) -> Vec<(&'builder str, String)> {
+        let mut query_params: Vec<_> = vec![];
+        if let Some(username) = self.base.username {
+            query_params.push(("username", username.to_string()));
+        }
+        if let Some(item_id) = self.base.item_id.or(self.params.item_id) {
+            query_params.push(("id", item_id.to_string()));
+        }
+        if let Some(min_date) = self.params.min_date {
+            query_params.push(("mindate", min_date.format("%Y-%m-%d").to_string()));
+        }
+        if let Some(max_date) = self.params.max_date {
+            query_params.push(("maxdate", max_date.format("%Y-%m-%d").to_string()));
+        }
+        if let Some(subtype) = self.params.subtype {
+            query_params.push(("subtype", subtype.to_string()));
+        }
+        if let Some(page) = self.params.page {
+            query_params.push(("page", page.to_string()));
+        }
+        query_params
+    }
+}
+
+/// Plays endpoint of the API. Used for returning the plays logged by a user,
+/// or the plays logged of a particular item.
+pub struct PlaysApi<'api> {
+    pub(crate) api: &'api BoardGameGeekApi,
+    endpoint: &'static str,
+}
+
+impl<'api> PlaysApi<'api> {
+    // The number of plays the API returns per page.
+    const PAGE_SIZE: u64 = 100;
+
+    pub(crate) fn new(api: &'api BoardGameGeekApi) -> Self {
+        Self {
+            api,
+            endpoint: "plays",
+        }
+    }
+
+    /// Gets a page of the plays logged by a user, the first page if none is set
+    /// in the query parameters.
+    pub async fn get_by_username(
+        &self,
+        username: &str,
+        query_params: PlaysQueryParams,
+    ) -> Result<Plays> {
+        let base = BasePlaysQuery {
+            username: Some(username),
+            item_id: None,
+        };
+        self.get_from_query(base, query_params).await
+    }
+
+    /// Gets a page of the plays logged of an item by all users, the first page if
+    /// none is set in the query parameters.
+    pub async fn get_by_item(&self, item_id: u64, query_params: PlaysQueryParams) -> Result<Plays> {
+        let base = BasePlaysQuery {
+            username: None,
+            item_id: Some(item_id),
+        };
+        self.get_from_query(base, query_params).await
+    }
+
+    /// Gets every play logged by a user.
+    ///
+    /// The plays are returned as a stream which requests each page in turn as it is
+    /// needed, starting from the page in the query, and ends once the total number of
+    /// plays reported by the API have been returned. If a request fails the error is
+    /// returned and the stream ends.
+    pub fn stream_by_username(
+        &self,
+        username: &'api str,
+        query_params: PlaysQueryParams,
+    ) -> impl Stream<Item = Result<Play>> + 'api {
+        let base = BasePlaysQuery {
+            username: Some(username),
+            item_id: None,
+        };
+        self.stream_from_query(base, query_params)
+    }
+
+    /// Gets every play logged of an item by all users.
+    ///
+    /// See [PlaysApi::stream_by_username] for how the pages are requested.
+    pub fn stream_by_item(
+        &self,
+        item_id: u64,
+        query_params: PlaysQueryParams,
+    ) -> impl Stream<Item = Result<Play>> + 'api {
+        let base = BasePlaysQuery {
+            username: None,
+            item_id: Some(item_id),
+        };
+        self.stream_from_query(base, query_params)
+    }
+
+    fn stream_from_query(
+        &self,
+        base: BasePlaysQuery<'api>,
+        query_params: PlaysQueryParams,
+    ) -> impl Stream<Item = Result<Play>> + 'api {
+        let api = self.api;
+        let first_page = query_params.page.unwrap_or(1);
+
+        stream_pages(first_page, Self::PAGE_SIZE, move |page| {
+            let base = base.clone();
+            let query_params = query_params.clone();
+            async move {
+                let plays = PlaysApi::new(api)
+                    .get_from_query(base, query_params.page(page))
+                    .await?;
+                Ok((plays.plays, plays.total))
+            }
+        })
+    }
+
+    async fn get_from_query(
+        &self,
+        base: BasePlaysQuery<'_>,
+        query_params: PlaysQueryParams,
+    ) -> Result<Plays> {
+        let query = PlaysQueryBuilder::new(base, query_params);
+
+        let request = self.api.build_request(self.endpoint, &query.build());
+        self.api.execute_request::<Plays>(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::StreamExt;
+    use mockito::Matcher;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn get_by_username() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/plays")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("username".into(), "bluebearbgg".into()),
+                Matcher::UrlEncoded("mindate".into(), "2024-01-01".into()),
+                Matcher::UrlEncoded("maxdate".into(), "2024-12-31".into()),
+                Matcher::UrlEncoded("subtype".into(), "boardgame".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/plays/plays_user.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let query = PlaysQueryParams::new()
+            .min_date(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+            .max_date(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap())
+            .subtype(ThingType::BoardGame);
+        let plays = api.plays().get_by_username("bluebearbgg", query).await;
+        mock.assert_async().await;
+
+        assert!(plays.is_ok(), "error returned when okay expected");
+        let plays = plays.unwrap();
+
+        assert_eq!(plays.username, Some("bluebearbgg".into()));
+        assert_eq!(plays.user_id, Some(2398714));
+        assert_eq!(plays.total, 101);
+        assert_eq!(plays.page, 1);
+        assert_eq!(
+            plays.plays,
+            vec![
+                Play {
+                    id: 83426548,
+                    date: NaiveDate::from_ymd_opt(2024, 4, 13).unwrap(),
+                    quantity: 1,
+                    length: Duration::minutes(75),
+                    incomplete: false,
+                    no_win_stats: false,
+                    location: Some("Home".into()),
+                    item: PlayItem {
+                        id: 312484,
+                        name: "Lost Ruins of Arnak".into(),
+                    },
+                    comments: Some("First game with the expansion leaders.".into()),
+                    players: vec![
+                        PlayPlayer {
+                            username: Some("bluebearbgg".into()),
+                            user_id: Some(2398714),
+                            name: "Matt".into(),
+                            start_position: Some("1".into()),
+                            color: Some("Red".into()),
+                            score: Some("54".into()),
+                            new: false,
+                            rating: Some(8.0),
+                            win: true,
+                        },
+                        PlayPlayer {
+                            username: None,
+                            user_id: None,
+                            name: "Sam".into(),
+                            start_position: Some("2".into()),
+                            color: Some("Blue".into()),
+                            score: Some("49".into()),
+                            new: true,
+                            rating: None,
+                            win: false,
+                        },
+                    ],
+                },
+                Play {
+                    id: 83411022,
+                    date: NaiveDate::from_ymd_opt(2024, 4, 10).unwrap(),
+                    quantity: 2,
+                    length: Duration::minutes(0),
+                    incomplete: true,
+                    no_win_stats: true,
+                    location: None,
+                    item: PlayItem {
+                        id: 131835,
+                        name: "Boss Monster: The Dungeon Building Card Game".into(),
+                    },
+                    comments: None,
+                    players: vec![],
+                },
+            ],
+        );
+    }
+
+    #[tokio::test]
+    async fn get_by_item() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/plays")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".into(), "312484".into()),
+                Matcher::UrlEncoded("page".into(), "3".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/plays/plays_item.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let plays = api
+            .plays()
+            .get_by_item(312484, PlaysQueryParams::new().page(3))
+            .await;
+        mock.assert_async().await;
+
+        assert!(plays.is_ok(), "error returned when okay expected");
+        let plays = plays.unwrap();
+
+        assert_eq!(plays.username, None);
+        assert_eq!(plays.user_id, None);
+        assert_eq!(plays.total, 1);
+        assert_eq!(plays.plays.len(), 1);
+        assert_eq!(plays.plays[0].item.id, 312484);
+    }
+
+    #[tokio::test]
+    async fn stream_by_username() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let first_mock = server
+            .mock("GET", "/plays")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("username".into(), "bluebearbgg".into()),
+                Matcher::UrlEncoded("id".into(), "312484".into()),
+                Matcher::UrlEncoded("page".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/plays/plays_user.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+        let second_mock = server
+            .mock("GET", "/plays")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("username".into(), "bluebearbgg".into()),
+                Matcher::UrlEncoded("id".into(), "312484".into()),
+                Matcher::UrlEncoded("page".into(), "2".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/plays/plays_user_page_2.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let plays: Vec<Result<Play>> = api
+            .plays()
+            .stream_by_username("bluebearbgg", PlaysQueryParams::new().item_id(312484))
+            .collect()
+            .await;
+        first_mock.assert_async().await;
+        second_mock.assert_async().await;
+
+        let ids: Vec<u64> = plays.into_iter().map(|play| play.unwrap().id).collect();
+        assert_eq!(ids, vec![83426548, 83411022, 71230945]);
+    }
+}
@@ -2,7 +2,7 @@ use core::fmt;
 
 use serde::Deserialize;
 
-use super::GameType;
+use super::{GameType, ThingType};
 use crate::utils::{XmlSignedValue, XmlStringValue};
 use crate::{BoardGameGeekApi, Result};
 
@@ -14,17 +14,18 @@ pub struct SearchResults {
     pub results: Vec<SearchResult>,
 }
 
-/// A result when searching for a name. Includes the game's name, type, and year published.
+/// A result when searching for a name. Includes the item's name, type, and year published.
 #[derive(Clone, Debug, PartialEq)]
 pub struct SearchResult {
-    /// The ID of the game.
+    /// The ID of the item.
     pub id: u64,
-    /// The type of game, which will either be boardgame or expansion.
-    pub item_type: GameType,
-    /// The name of the game.
+    /// The type of item, such as a board game, expansion or role playing game item.
+    pub item_type: ThingType,
+    /// The name of the item.
     pub name: String,
-    /// The year the game was first published.
-    pub year_published: i64,
+    /// The year the item was first published. Omitted if the item has no year of
+    /// publication.
+    pub year_published: Option<i64>,
 }
 
 impl<'de> Deserialize<'de> for SearchResult {
@@ -96,8 +97,6 @@ impl<'de> Deserialize<'de> for SearchResult {
                 let item_type =
                     item_type.ok_or_else(|| serde::de::Error::missing_field("item_type"))?;
                 let name = name.ok_or_else(|| serde::de::Error::missing_field("name"))?;
-                let year_published = year_published
-                    .ok_or_else(|| serde::de::Error::missing_field("yearpublished"))?;
                 Ok(Self::Value {
                     id,
                     item_type,
@@ -120,13 +119,13 @@ pub struct BaseSearchQuery<'q> {
 /// search endpoint.
 #[derive(Clone, Debug, Default)]
 pub struct SearchQueryParams {
-    /// Include only results for this game type.
+    /// Include only results for these item types. All types are included if empty.
     ///
-    /// Note, if this is set to [GameType::BoardGame] then it will include both
+    /// Note, if this includes [ThingType::BoardGame] then it will include both
     /// board games and expansions, but set the type of all of them to be
-    /// [GameType::BoardGame] in the results. There does not seem to be a way around
+    /// [ThingType::BoardGame] in the results. There does not seem to be a way around
     /// this.
-    game_type: Option<GameType>,
+    item_types: Vec<ThingType>,
     /// Limit results to only exact matches of the search query.
     exact: Option<bool>,
 }
@@ -137,12 +136,20 @@ impl SearchQueryParams {
         Self::default()
     }
 
-    /// Sets the game_type query param, so that only expansions or board games can be filtered when searching.
-    pub fn game_type(mut self, game_type: GameType) -> Self {
-        self.game_type = Some(game_type);
+    /// Adds to the item_types query param, so that only items of the given types are
+    /// returned when searching. Can be called multiple times to include several types.
+    pub fn item_type(mut self, item_type: ThingType) -> Self {
+        self.item_types.push(item_type);
         self
     }
 
+    /// Sets the game_type query param, so that only expansions or board games can be
+    /// filtered when searching.
+    #[deprecated(note = "use `item_type` instead, which supports all types of item")]
+    pub fn game_type(self, game_type: GameType) -> Self {
+        self.item_type(game_type)
+    }
+
     /// Sets the exact query param, so that exact matches will be returned if set to true.
     pub fn exact(mut self, exact: bool) -> Self {
         self.exact = Some(exact);
@@ -172,12 +179,14 @@ impl<'builder> SearchQueryBuilder<'builder> {
             Some(false) => query_params.push(("exact", "0".to_string())),
             None => {}
         }
-        match self.params.game_type {
-            Some(GameType::BoardGame) => query_params.push(("type", "boardgame".to_string())),
-            Some(GameType::BoardGameExpansion) => {
-                query_params.push(("type", "boardgameexpansion".to_string()))
-            }
-            None => {}
+        if !self.params.item_types.is_empty() {
+            let item_types: Vec<String> = self
+                .params
+                .item_types
+                .iter()
+                .map(ThingType::to_string)
+                .collect();
+            query_params.push(("type", item_types.join(",")));
         }
         query_params
     }
@@ -238,10 +247,10 @@ mod tests {
     #[tokio::test]
     async fn search() {
         let mut server = mockito::Server::new_async().await;
-        let api = BoardGameGeekApi {
-            base_url: server.url(),
-            client: reqwest::Client::new(),
-        };
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
 
         let mock = server
             .mock("GET", "/search")
@@ -268,18 +277,18 @@ mod tests {
             search_results.results[0],
             SearchResult {
                 id: 312484,
-                item_type: GameType::BoardGame,
+                item_type: ThingType::BoardGame,
                 name: "Lost Ruins of Arnak".into(),
-                year_published: 2020,
+                year_published: Some(2020),
             },
         );
         assert_eq!(
             search_results.results[1],
             SearchResult {
                 id: 341254,
-                item_type: GameType::BoardGameExpansion,
+                item_type: ThingType::BoardGameExpansion,
                 name: "Lost Ruins of Arnak: Expedition Leaders".into(),
-                year_published: 2021,
+                year_published: Some(2021),
             },
         );
     }
@@ -287,10 +296,10 @@ mod tests {
     #[tokio::test]
     async fn search_exact() {
         let mut server = mockito::Server::new_async().await;
-        let api = BoardGameGeekApi {
-            base_url: server.url(),
-            client: reqwest::Client::new(),
-        };
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
 
         let mock = server
             .mock("GET", "/search")
@@ -317,9 +326,9 @@ mod tests {
             search_results.results[0],
             SearchResult {
                 id: 312484,
-                item_type: GameType::BoardGame,
+                item_type: ThingType::BoardGame,
                 name: "Lost Ruins of Arnak".into(),
-                year_published: 2020,
+                year_published: Some(2020),
             },
         );
     }
@@ -327,10 +336,10 @@ mod tests {
     #[tokio::test]
     async fn search_with_query_params() {
         let mut server = mockito::Server::new_async().await;
-        let api = BoardGameGeekApi {
-            base_url: server.url(),
-            client: reqwest::Client::new(),
-        };
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
 
         let mock = server
             .mock("GET", "/search")
@@ -352,7 +361,7 @@ mod tests {
             .search_with_query_params(
                 "arnak",
                 SearchQueryParams::new()
-                    .game_type(GameType::BoardGameExpansion)
+                    .item_type(ThingType::BoardGameExpansion)
                     .exact(false),
             )
             .await;
@@ -366,9 +375,9 @@ mod tests {
             search_results.results[0],
             SearchResult {
                 id: 341254,
-                item_type: GameType::BoardGameExpansion,
+                item_type: ThingType::BoardGameExpansion,
                 name: "Lost Ruins of Arnak: Expedition Leaders".into(),
-                year_published: 2021,
+                year_published: Some(2021),
             },
         );
 
@@ -393,7 +402,7 @@ mod tests {
                 "lost ruins of arnak",
                 SearchQueryParams::new()
                     .exact(true)
-                    .game_type(GameType::BoardGame),
+                    .item_type(ThingType::BoardGame),
             )
             .await;
         mock.assert_async().await;
@@ -406,10 +415,133 @@ mod tests {
             search_results.results[0],
             SearchResult {
                 id: 312484,
-                item_type: GameType::BoardGame,
+                item_type: ThingType::BoardGame,
                 name: "Lost Ruins of Arnak".into(),
-                year_published: 2020,
+                year_published: Some(2020),
+            },
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn search_query_game_type() {
+        let query = SearchQueryBuilder::new(
+            BaseSearchQuery {
+                query: "lost ruins of arnak",
             },
+            SearchQueryParams::new().game_type(GameType::BoardGameExpansion),
+        );
+
+        assert_eq!(
+            query.build(),
+            vec![
+                ("query", "lost ruins of arnak".to_string()),
+                ("type", "boardgameexpansion".to_string()),
+            ],
+        );
+    }
+
+    #[tokio::test]
+    async fn search_multiple_types() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/search")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("query".into(), "lost".into()),
+                Matcher::UrlEncoded("type".into(), "rpgitem,videogame".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/search/search_multiple_types.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let search_results = api
+            .search()
+            .search_with_query_params(
+                "lost",
+                SearchQueryParams::new()
+                    .item_type(ThingType::RpgItem)
+                    .item_type(ThingType::VideoGame),
+            )
+            .await;
+        mock.assert_async().await;
+
+        assert!(search_results.is_ok(), "error returned when okay expected");
+        assert_eq!(
+            search_results.unwrap().results,
+            vec![
+                SearchResult {
+                    id: 171604,
+                    item_type: ThingType::RpgItem,
+                    name: "Lost Mine of Phandelver".into(),
+                    year_published: Some(2014),
+                },
+                SearchResult {
+                    id: 205093,
+                    item_type: ThingType::VideoGame,
+                    name: "Lost Ruins".into(),
+                    year_published: None,
+                },
+            ],
+        );
+    }
+
+    #[tokio::test]
+    async fn search_other_types() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/search")
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "query".into(),
+                "lost".into(),
+            )]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/search/search_other_types.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let search_results = api.search().search("lost").await;
+        mock.assert_async().await;
+
+        assert!(search_results.is_ok(), "error returned when okay expected");
+        assert_eq!(
+            search_results.unwrap().results,
+            vec![
+                SearchResult {
+                    id: 61380,
+                    item_type: ThingType::BoardGameFamily,
+                    name: "Series: Lost Ruins of Arnak".into(),
+                    year_published: None,
+                },
+                SearchResult {
+                    id: 69224,
+                    item_type: ThingType::VideoGameHardware,
+                    name: "Lost Handheld".into(),
+                    year_published: Some(2004),
+                },
+                SearchResult {
+                    id: 97361,
+                    item_type: ThingType::Other,
+                    name: "Lost Ruins of Arnak: Best Family Game".into(),
+                    year_published: None,
+                },
+            ],
         );
     }
 }
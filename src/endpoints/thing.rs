@@ -0,0 +1,2434 @@
+use core::fmt;
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Duration, Utc};
+use futures_util::{stream, Stream, StreamExt, TryStreamExt};
+use serde::Deserialize;
+
+use super::{GameTypeRank, Ranks, ThingType};
+use crate::utils::{
+    deserialize_optional_rating, deserialize_true_false_bool, rfc2822_date_deserializer,
+    stream_pages, NameType, XmlFloatValue, XmlIntValue, XmlMinutesValue, XmlName, XmlSignedValue,
+    XmlStringValue,
+};
+use crate::{BoardGameGeekApi, Error, Result};
+
+/// The returned struct containing a list of things.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Things {
+    /// The list of things returned, in the order the API returned them.
+    #[serde(default, rename = "$value")]
+    pub items: Vec<Thing>,
+}
+
+/// The result of requesting many things at once by their IDs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThingBatch {
+    /// The things that were found, in the same order as the IDs were requested.
+    /// Any duplicate IDs in the request are only returned once.
+    pub items: Vec<Thing>,
+    /// The requested IDs that the API did not return a thing for, in the same
+    /// order as they were requested.
+    pub missing_ids: Vec<u64>,
+}
+
+/// A game or expansion, with the full details returned by the thing endpoint.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Thing {
+    /// The ID of the game.
+    pub id: u64,
+    /// The type of the item, such as a board game, expansion, accessory, or role
+    /// playing game item.
+    pub item_type: ThingType,
+    /// The primary name of the game.
+    pub name: String,
+    /// Any other names the game is known by, such as translations.
+    pub alternate_names: Vec<String>,
+    /// The description of the game.
+    pub description: String,
+    /// A link to a jpg image for the game. Omitted if the game has no image.
+    pub image: Option<String>,
+    /// A link to a jpg thumbnail image for the game. Omitted if the game has no image.
+    pub thumbnail: Option<String>,
+    /// The year the game was first published. Omitted for item types without a
+    /// publication year, such as some role playing game items.
+    pub year_published: Option<i64>,
+    /// Minimum players the game supports. Omitted for item types without a player
+    /// count, such as video games.
+    pub min_players: Option<u32>,
+    /// Maximum players the game supports. Omitted for item types without a player
+    /// count, such as video games.
+    pub max_players: Option<u32>,
+    /// The community poll on which player counts the game is best with. Only
+    /// included for item types that have the poll.
+    pub suggested_player_count: Option<PlayerCountPoll>,
+    /// The amount of time the game is suggested to take to play. Omitted for item
+    /// types without a playing time, such as video games.
+    pub playing_time: Option<Duration>,
+    /// Minimum amount of time the game is suggested to take to play. Omitted for
+    /// item types without a playing time.
+    pub min_playtime: Option<Duration>,
+    /// Maximum amount of time the game is suggested to take to play. Omitted for
+    /// item types without a playing time.
+    pub max_playtime: Option<Duration>,
+    /// The minimum suggested age to play the game. Omitted for item types without
+    /// a suggested age, such as video games.
+    pub min_age: Option<u32>,
+    /// Links to other items related to this game, such as its designers,
+    /// publishers, categories, mechanics and expansions.
+    pub links: Vec<Link>,
+    /// All the printed editions of the game. Only included if requested, otherwise
+    /// this is empty.
+    pub versions: Vec<Version>,
+    /// A page of the comments users have left on the game. Only included if
+    /// requested.
+    pub comments: Option<CommentPage>,
+    /// The listings for this game on the marketplace. Only included if requested,
+    /// otherwise this is empty.
+    pub marketplace_listings: Vec<MarketplaceListing>,
+    /// Stats about the game from all users on the site. Only included if
+    /// requested.
+    pub stats: Option<ThingStats>,
+}
+
+impl<'de> Deserialize<'de> for Thing {
+    fn deserialize<D: serde::de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(field_identifier, rename_all = "lowercase")]
+        enum Field {
+            ID,
+            Type,
+            Name,
+            Description,
+            Image,
+            Thumbnail,
+            YearPublished,
+            MinPlayers,
+            MaxPlayers,
+            Poll,
+            PlayingTime,
+            MinPlayTime,
+            MaxPlayTime,
+            MinAge,
+            Link,
+            Versions,
+            Comments,
+            MarketplaceListings,
+            Statistics,
+            #[serde(other)]
+            Unknown,
+        }
+
+        struct ThingVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ThingVisitor {
+            type Value = Thing;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a string containing the XML for a thing.")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> core::result::Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut id = None;
+                let mut item_type = None;
+                let mut name = None;
+                let mut alternate_names = vec![];
+                let mut description = None;
+                let mut image = None;
+                let mut thumbnail = None;
+                let mut year_published = None;
+                let mut min_players = None;
+                let mut max_players = None;
+                let mut suggested_player_count = None;
+                let mut playing_time = None;
+                let mut min_playtime = None;
+                let mut max_playtime = None;
+                let mut min_age = None;
+                let mut links = vec![];
+                let mut versions = None;
+                let mut comments = None;
+                let mut marketplace_listings = None;
+                let mut stats = None;
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Field::ID => {
+                            if id.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            let id_str: String = map.next_value()?;
+                            id = Some(id_str.parse::<u64>().map_err(|e| {
+                                serde::de::Error::custom(format!(
+                                    "failed to parse value a u64: {e}"
+                                ))
+                            })?);
+                        }
+                        Field::Type => {
+                            if item_type.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            item_type = Some(map.next_value()?);
+                        }
+                        Field::Name => {
+                            let name_xml: XmlName = map.next_value()?;
+                            match name_xml.name_type {
+                                NameType::Primary => {
+                                    if name.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("name"));
+                                    }
+                                    name = Some(name_xml.value);
+                                }
+                                NameType::Alternate => {
+                                    alternate_names.push(name_xml.value);
+                                }
+                            }
+                        }
+                        Field::Description => {
+                            if description.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description = Some(map.next_value()?);
+                        }
+                        Field::Image => {
+                            if image.is_some() {
+                                return Err(serde::de::Error::duplicate_field("image"));
+                            }
+                            image = Some(map.next_value()?);
+                        }
+                        Field::Thumbnail => {
+                            if thumbnail.is_some() {
+                                return Err(serde::de::Error::duplicate_field("thumbnail"));
+                            }
+                            thumbnail = Some(map.next_value()?);
+                        }
+                        Field::YearPublished => {
+                            if year_published.is_some() {
+                                return Err(serde::de::Error::duplicate_field("yearpublished"));
+                            }
+                            let year_published_xml_tag: XmlSignedValue = map.next_value()?;
+                            year_published = Some(year_published_xml_tag.value);
+                        }
+                        Field::MinPlayers => {
+                            if min_players.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minplayers"));
+                            }
+                            let min_players_xml_tag: XmlIntValue = map.next_value()?;
+                            min_players = Some(min_players_xml_tag.value as u32);
+                        }
+                        Field::MaxPlayers => {
+                            if max_players.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxplayers"));
+                            }
+                            let max_players_xml_tag: XmlIntValue = map.next_value()?;
+                            max_players = Some(max_players_xml_tag.value as u32);
+                        }
+                        Field::Poll => {
+                            // The item includes several polls, of which only the suggested
+                            // player count poll is currently parsed.
+                            let poll: XmlPoll = map.next_value()?;
+                            if poll.name == "suggested_numplayers" {
+                                if suggested_player_count.is_some() {
+                                    return Err(serde::de::Error::duplicate_field(
+                                        "suggested_numplayers",
+                                    ));
+                                }
+                                suggested_player_count = Some(
+                                    PlayerCountPoll::try_from(poll)
+                                        .map_err(serde::de::Error::custom)?,
+                                );
+                            }
+                        }
+                        Field::PlayingTime => {
+                            if playing_time.is_some() {
+                                return Err(serde::de::Error::duplicate_field("playingtime"));
+                            }
+                            let playing_time_xml_tag: XmlMinutesValue = map.next_value()?;
+                            playing_time = Some(playing_time_xml_tag.value);
+                        }
+                        Field::MinPlayTime => {
+                            if min_playtime.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minplaytime"));
+                            }
+                            let min_playtime_xml_tag: XmlMinutesValue = map.next_value()?;
+                            min_playtime = Some(min_playtime_xml_tag.value);
+                        }
+                        Field::MaxPlayTime => {
+                            if max_playtime.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxplaytime"));
+                            }
+                            let max_playtime_xml_tag: XmlMinutesValue = map.next_value()?;
+                            max_playtime = Some(max_playtime_xml_tag.value);
+                        }
+                        Field::MinAge => {
+                            if min_age.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minage"));
+                            }
+                            let min_age_xml_tag: XmlIntValue = map.next_value()?;
+                            min_age = Some(min_age_xml_tag.value as u32);
+                        }
+                        Field::Link => {
+                            links.push(map.next_value()?);
+                        }
+                        Field::Versions => {
+                            if versions.is_some() {
+                                return Err(serde::de::Error::duplicate_field("versions"));
+                            }
+                            // An extra layer of indirection is needed due to the way the XML is structured,
+                            // but should be removed for the final structure.
+                            let versions_struct: Versions = map.next_value()?;
+                            versions = Some(versions_struct.versions);
+                        }
+                        Field::Comments => {
+                            if comments.is_some() {
+                                return Err(serde::de::Error::duplicate_field("comments"));
+                            }
+                            comments = Some(map.next_value()?);
+                        }
+                        Field::MarketplaceListings => {
+                            if marketplace_listings.is_some() {
+                                return Err(serde::de::Error::duplicate_field(
+                                    "marketplacelistings",
+                                ));
+                            }
+                            // An extra layer of indirection is needed due to the way the XML is structured,
+                            // but should be removed for the final structure.
+                            let listings: MarketplaceListings = map.next_value()?;
+                            marketplace_listings = Some(listings.listings);
+                        }
+                        Field::Statistics => {
+                            if stats.is_some() {
+                                return Err(serde::de::Error::duplicate_field("statistics"));
+                            }
+                            // An extra layer of indirection is needed due to the way the XML is structured,
+                            // but should be removed for the final structure.
+                            let statistics: Statistics = map.next_value()?;
+                            stats = Some(statistics.ratings);
+                        }
+                        Field::Unknown => {
+                            map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let id = id.ok_or_else(|| serde::de::Error::missing_field("id"))?;
+                let item_type =
+                    item_type.ok_or_else(|| serde::de::Error::missing_field("item_type"))?;
+                let name = name.ok_or_else(|| serde::de::Error::missing_field("name"))?;
+                let description =
+                    description.ok_or_else(|| serde::de::Error::missing_field("description"))?;
+                Ok(Self::Value {
+                    id,
+                    item_type,
+                    name,
+                    alternate_names,
+                    description,
+                    image,
+                    thumbnail,
+                    year_published,
+                    min_players,
+                    max_players,
+                    suggested_player_count,
+                    playing_time,
+                    min_playtime,
+                    max_playtime,
+                    min_age,
+                    links,
+                    versions: versions.unwrap_or_default(),
+                    comments,
+                    marketplace_listings: marketplace_listings.unwrap_or_default(),
+                    stats,
+                })
+            }
+        }
+        deserializer.deserialize_any(ThingVisitor)
+    }
+}
+
+/// A page of comments that users have left on a game, along with the total
+/// number of comments across all pages.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct CommentPage {
+    /// The page number, starting from 1.
+    pub page: u64,
+    /// The total number of comments across all pages.
+    #[serde(rename = "totalitems")]
+    pub total_items: u64,
+    /// The comments on this page.
+    #[serde(default, rename = "$value")]
+    pub comments: Vec<Comment>,
+}
+
+/// A comment and/or rating that a user has left on a game.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Comment {
+    /// The username of the user who left the comment.
+    pub username: String,
+    /// The 0-10 rating that the user gave the game, if they rated it.
+    #[serde(deserialize_with = "deserialize_optional_rating")]
+    pub rating: Option<f64>,
+    /// The text of the comment, which can be empty if the user only rated the game.
+    #[serde(rename = "value")]
+    pub text: String,
+}
+
+// Intermediary struct needed due to the way the XML is structured.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+struct MarketplaceListings {
+    #[serde(default, rename = "$value")]
+    listings: Vec<MarketplaceListing>,
+}
+
+/// A listing for a game on the marketplace.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarketplaceListing {
+    /// When the game was listed.
+    pub list_date: DateTime<Utc>,
+    /// The price the game is listed for.
+    pub price: Price,
+    /// The condition of the game being sold.
+    pub condition: ItemCondition,
+    /// Notes from the seller, which can be empty.
+    pub notes: String,
+    /// A link to the listing on the marketplace.
+    pub link: String,
+}
+
+impl<'de> Deserialize<'de> for MarketplaceListing {
+    fn deserialize<D: serde::de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(field_identifier, rename_all = "lowercase")]
+        enum Field {
+            ListDate,
+            Price,
+            Condition,
+            Notes,
+            Link,
+            #[serde(other)]
+            Unknown,
+        }
+
+        // The link tag has the URL as an href rather than a value.
+        #[derive(Deserialize)]
+        struct XmlLink {
+            href: String,
+        }
+
+        struct MarketplaceListingVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for MarketplaceListingVisitor {
+            type Value = MarketplaceListing;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a string containing the XML for a marketplace listing.")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> core::result::Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut list_date = None;
+                let mut price = None;
+                let mut condition = None;
+                let mut notes = None;
+                let mut link = None;
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Field::ListDate => {
+                            if list_date.is_some() {
+                                return Err(serde::de::Error::duplicate_field("listdate"));
+                            }
+                            let list_date_xml_tag: XmlStringValue = map.next_value()?;
+                            list_date = Some(
+                                rfc2822_date_deserializer::parse(&list_date_xml_tag.value)
+                                    .map_err(serde::de::Error::custom)?,
+                            );
+                        }
+                        Field::Price => {
+                            if price.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
+                            }
+                            let price_xml_tag: XmlPrice = map.next_value()?;
+                            price = Some(
+                                Price::new(&price_xml_tag.value, price_xml_tag.currency)
+                                    .map_err(serde::de::Error::custom)?,
+                            );
+                        }
+                        Field::Condition => {
+                            if condition.is_some() {
+                                return Err(serde::de::Error::duplicate_field("condition"));
+                            }
+                            let condition_xml_tag: XmlCondition = map.next_value()?;
+                            condition = Some(condition_xml_tag.value);
+                        }
+                        Field::Notes => {
+                            if notes.is_some() {
+                                return Err(serde::de::Error::duplicate_field("notes"));
+                            }
+                            let notes_xml_tag: XmlStringValue = map.next_value()?;
+                            notes = Some(notes_xml_tag.value);
+                        }
+                        Field::Link => {
+                            if link.is_some() {
+                                return Err(serde::de::Error::duplicate_field("link"));
+                            }
+                            let link_xml_tag: XmlLink = map.next_value()?;
+                            link = Some(link_xml_tag.href);
+                        }
+                        Field::Unknown => {
+                            map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let list_date =
+                    list_date.ok_or_else(|| serde::de::Error::missing_field("listdate"))?;
+                let price = price.ok_or_else(|| serde::de::Error::missing_field("price"))?;
+                let condition =
+                    condition.ok_or_else(|| serde::de::Error::missing_field("condition"))?;
+                let notes = notes.ok_or_else(|| serde::de::Error::missing_field("notes"))?;
+                let link = link.ok_or_else(|| serde::de::Error::missing_field("link"))?;
+                Ok(Self::Value {
+                    list_date,
+                    price,
+                    condition,
+                    notes,
+                    link,
+                })
+            }
+        }
+        deserializer.deserialize_any(MarketplaceListingVisitor)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlPrice {
+    currency: String,
+    value: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlCondition {
+    value: ItemCondition,
+}
+
+/// The condition of a copy of a game.
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq)]
+pub enum ItemCondition {
+    /// Brand new, typically still in shrink wrap.
+    #[serde(rename = "new")]
+    New,
+    /// Used but in the same condition as new.
+    #[serde(rename = "likenew")]
+    LikeNew,
+    /// Used with very minor wear.
+    #[serde(rename = "verygood")]
+    VeryGood,
+    /// Used with some wear.
+    #[serde(rename = "good")]
+    Good,
+    /// Used with significant wear, but complete and playable.
+    #[serde(rename = "acceptable")]
+    Acceptable,
+}
+
+/// An amount of money in a particular currency.
+///
+/// The amount is stored as a whole number of hundredths of the currency's main unit,
+/// such as cents, to avoid the rounding errors that come with floating point numbers.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Price {
+    /// The amount in hundredths of the currency's main unit, so 45.50 is 4550.
+    pub minor_units: i64,
+    /// The three letter ISO 4217 code of the currency, such as "USD".
+    pub currency: String,
+}
+
+impl Price {
+    // Parses a decimal amount string, such as "45.50", with up to two decimal places.
+    pub(crate) fn new(amount: &str, currency: String) -> core::result::Result<Self, String> {
+        let invalid = || format!("invalid price amount: {amount}");
+        let (negative, unsigned) = match amount.trim().strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, amount.trim()),
+        };
+        let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        if whole.is_empty()
+            || fraction.len() > 2
+            || !whole
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+        let whole: i64 = whole.parse().map_err(|_| invalid())?;
+        let fraction: i64 = format!("{fraction:0<2}").parse().map_err(|_| invalid())?;
+        let minor_units = whole
+            .checked_mul(100)
+            .and_then(|units| units.checked_add(fraction))
+            .ok_or_else(invalid)?;
+        Ok(Self {
+            minor_units: if negative { -minor_units } else { minor_units },
+            currency,
+        })
+    }
+
+    // Parses a decimal amount string entered by a user, rounding to the nearest minor
+    // unit if it has more than two decimal places. Returns None if it can't be parsed.
+    pub(crate) fn new_rounded(amount: &str, currency: String) -> Option<Self> {
+        let amount = amount.trim();
+        let Some((whole, fraction)) = amount
+            .split_once('.')
+            .filter(|(_, fraction)| fraction.len() > 2)
+        else {
+            return Self::new(amount, currency).ok();
+        };
+        if !fraction.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let mut price = Self::new(&format!("{whole}.{}", &fraction[..2]), currency).ok()?;
+        if fraction.as_bytes()[2] >= b'5' {
+            let step = if whole.starts_with('-') { -1 } else { 1 };
+            price.minor_units = price.minor_units.checked_add(step)?;
+        }
+        Some(price)
+    }
+
+    // Formats the amount as a decimal string without the currency, such as "45.50".
+    pub(crate) fn amount(&self) -> String {
+        let sign = if self.minor_units < 0 { "-" } else { "" };
+        let units = self.minor_units.unsigned_abs();
+        format!("{sign}{}.{:02}", units / 100, units % 100)
+    }
+}
+
+impl fmt::Display for Price {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.amount(), self.currency)
+    }
+}
+
+// Intermediary struct needed due to the way the XML is structured.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+struct Versions {
+    #[serde(default, rename = "$value")]
+    versions: Vec<Version>,
+}
+
+/// A printed edition of a game, with its publishers, language and box dimensions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Version {
+    /// The ID of the version.
+    pub id: u64,
+    /// The name of the version, such as "English edition".
+    pub name: String,
+    /// Any other names the version is known by.
+    pub alternate_names: Vec<String>,
+    /// A link to a jpg image for the version. Omitted if the version has no image.
+    pub image: Option<String>,
+    /// A link to a jpg thumbnail image for the version. Omitted if the version has no image.
+    pub thumbnail: Option<String>,
+    /// The year this version was released.
+    pub year_published: i64,
+    /// The publisher's product code for this version, if known.
+    pub product_code: Option<String>,
+    /// The width of the box in inches, if known.
+    pub width: Option<f64>,
+    /// The length of the box in inches, if known.
+    pub length: Option<f64>,
+    /// The depth of the box in inches, if known.
+    pub depth: Option<f64>,
+    /// The weight of the box in pounds, if known.
+    pub weight: Option<f64>,
+    /// Links to the items related to this version, including the game it is a
+    /// version of, its publishers, artists and languages.
+    pub links: Vec<Link>,
+}
+
+impl Version {
+    /// The publishers of this version.
+    pub fn publishers(&self) -> impl Iterator<Item = &Link> {
+        self.links
+            .iter()
+            .filter(|link| link.link_type == LinkType::Publisher)
+    }
+
+    /// The languages this version is printed in.
+    pub fn languages(&self) -> impl Iterator<Item = &Link> {
+        self.links
+            .iter()
+            .filter(|link| link.link_type == LinkType::Language)
+    }
+}
+
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D: serde::de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(field_identifier, rename_all = "lowercase")]
+        enum Field {
+            ID,
+            Name,
+            Image,
+            Thumbnail,
+            YearPublished,
+            ProductCode,
+            Width,
+            Length,
+            Depth,
+            Weight,
+            Link,
+            #[serde(other)]
+            Unknown,
+        }
+
+        struct VersionVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for VersionVisitor {
+            type Value = Version;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a string containing the XML for a version of a thing.")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> core::result::Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut id = None;
+                let mut name = None;
+                let mut alternate_names = vec![];
+                let mut image = None;
+                let mut thumbnail = None;
+                let mut year_published = None;
+                let mut product_code = None;
+                let mut width = None;
+                let mut length = None;
+                let mut depth = None;
+                let mut weight = None;
+                let mut links = vec![];
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Field::ID => {
+                            if id.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            let id_str: String = map.next_value()?;
+                            id = Some(id_str.parse::<u64>().map_err(|e| {
+                                serde::de::Error::custom(format!(
+                                    "failed to parse value a u64: {e}"
+                                ))
+                            })?);
+                        }
+                        Field::Name => {
+                            let name_xml: XmlName = map.next_value()?;
+                            match name_xml.name_type {
+                                NameType::Primary => {
+                                    if name.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("name"));
+                                    }
+                                    name = Some(name_xml.value);
+                                }
+                                NameType::Alternate => {
+                                    alternate_names.push(name_xml.value);
+                                }
+                            }
+                        }
+                        Field::Image => {
+                            if image.is_some() {
+                                return Err(serde::de::Error::duplicate_field("image"));
+                            }
+                            image = Some(map.next_value()?);
+                        }
+                        Field::Thumbnail => {
+                            if thumbnail.is_some() {
+                                return Err(serde::de::Error::duplicate_field("thumbnail"));
+                            }
+                            thumbnail = Some(map.next_value()?);
+                        }
+                        Field::YearPublished => {
+                            if year_published.is_some() {
+                                return Err(serde::de::Error::duplicate_field("yearpublished"));
+                            }
+                            let year_published_xml_tag: XmlSignedValue = map.next_value()?;
+                            year_published = Some(year_published_xml_tag.value);
+                        }
+                        Field::ProductCode => {
+                            if product_code.is_some() {
+                                return Err(serde::de::Error::duplicate_field("productcode"));
+                            }
+                            let product_code_xml_tag: XmlStringValue = map.next_value()?;
+                            product_code = Some(product_code_xml_tag.value);
+                        }
+                        Field::Width => {
+                            if width.is_some() {
+                                return Err(serde::de::Error::duplicate_field("width"));
+                            }
+                            let width_xml_tag: XmlFloatValue = map.next_value()?;
+                            width = Some(width_xml_tag.value);
+                        }
+                        Field::Length => {
+                            if length.is_some() {
+                                return Err(serde::de::Error::duplicate_field("length"));
+                            }
+                            let length_xml_tag: XmlFloatValue = map.next_value()?;
+                            length = Some(length_xml_tag.value);
+                        }
+                        Field::Depth => {
+                            if depth.is_some() {
+                                return Err(serde::de::Error::duplicate_field("depth"));
+                            }
+                            let depth_xml_tag: XmlFloatValue = map.next_value()?;
+                            depth = Some(depth_xml_tag.value);
+                        }
+                        Field::Weight => {
+                            if weight.is_some() {
+                                return Err(serde::de::Error::duplicate_field("weight"));
+                            }
+                            let weight_xml_tag: XmlFloatValue = map.next_value()?;
+                            weight = Some(weight_xml_tag.value);
+                        }
+                        Field::Link => {
+                            links.push(map.next_value()?);
+                        }
+                        Field::Unknown => {
+                            map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let id = id.ok_or_else(|| serde::de::Error::missing_field("id"))?;
+                let name = name.ok_or_else(|| serde::de::Error::missing_field("name"))?;
+                let year_published = year_published
+                    .ok_or_else(|| serde::de::Error::missing_field("yearpublished"))?;
+                // The API returns empty values, or a 0 for the dimensions, when they are not known.
+                let product_code = product_code.filter(|code: &String| !code.is_empty());
+                Ok(Self::Value {
+                    id,
+                    name,
+                    alternate_names,
+                    image,
+                    thumbnail,
+                    year_published,
+                    product_code,
+                    width: width.filter(|width| *width > 0.0),
+                    length: length.filter(|length| *length > 0.0),
+                    depth: depth.filter(|depth| *depth > 0.0),
+                    weight: weight.filter(|weight| *weight > 0.0),
+                    links,
+                })
+            }
+        }
+        deserializer.deserialize_any(VersionVisitor)
+    }
+}
+
+/// The community poll on which player counts a game is best with. For each player
+/// count, users vote on whether the game is best, recommended, or not recommended.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlayerCountPoll {
+    /// The total number of users who voted in the poll.
+    pub total_votes: u64,
+    /// The votes for each player count, in the order returned by the API.
+    pub results: Vec<PlayerCountPollResult>,
+}
+
+impl PlayerCountPoll {
+    /// Gets the votes for an exact player count, if the poll has an entry for it.
+    pub fn result_for(&self, player_count: u32) -> Option<&PlayerCountPollResult> {
+        self.results
+            .iter()
+            .find(|result| result.player_count == PlayerCount::Exactly(player_count))
+    }
+
+    /// Whether more users voted that the game is best at this player count than voted
+    /// for either of the other options.
+    pub fn is_best_at(&self, player_count: u32) -> bool {
+        self.result_for(player_count)
+            .is_some_and(PlayerCountPollResult::is_best)
+    }
+
+    /// Whether a majority of users voted that the game is either best or recommended
+    /// at this player count.
+    pub fn is_recommended_at(&self, player_count: u32) -> bool {
+        self.result_for(player_count)
+            .is_some_and(PlayerCountPollResult::is_recommended)
+    }
+
+    /// All of the exact player counts that the game is voted best at.
+    pub fn best_player_counts(&self) -> Vec<u32> {
+        self.exact_player_counts_where(PlayerCountPollResult::is_best)
+    }
+
+    /// All of the exact player counts that the game is voted best or recommended at.
+    pub fn recommended_player_counts(&self) -> Vec<u32> {
+        self.exact_player_counts_where(PlayerCountPollResult::is_recommended)
+    }
+
+    fn exact_player_counts_where(&self, predicate: fn(&PlayerCountPollResult) -> bool) -> Vec<u32> {
+        self.results
+            .iter()
+            .filter(|result| predicate(result))
+            .filter_map(|result| match result.player_count {
+                PlayerCount::Exactly(player_count) => Some(player_count),
+                PlayerCount::MoreThan(_) => None,
+            })
+            .collect()
+    }
+}
+
+/// The votes for a single player count in a [PlayerCountPoll].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlayerCountPollResult {
+    /// The player count these votes are for.
+    pub player_count: PlayerCount,
+    /// The number of users who voted the game is best at this player count.
+    pub best: u64,
+    /// The number of users who voted the game is recommended at this player count.
+    pub recommended: u64,
+    /// The number of users who voted the game is not recommended at this player count.
+    pub not_recommended: u64,
+}
+
+impl PlayerCountPollResult {
+    /// The total number of votes for this player count.
+    pub fn total_votes(&self) -> u64 {
+        self.best + self.recommended + self.not_recommended
+    }
+
+    /// Whether best got more votes than either recommended or not recommended, even if
+    /// that is less than half of the votes.
+    pub fn is_best(&self) -> bool {
+        self.best > self.recommended && self.best > self.not_recommended
+    }
+
+    /// Whether more than half of the votes were for best or recommended.
+    pub fn is_recommended(&self) -> bool {
+        (self.best + self.recommended) * 2 > self.total_votes()
+    }
+}
+
+/// A player count that a [PlayerCountPollResult] is for.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PlayerCount {
+    /// Exactly this many players.
+    Exactly(u32),
+    /// More than this many players. The API returns this as "N+" for a count one above
+    /// the maximum number of players the game supports.
+    MoreThan(u32),
+}
+
+// Intermediary structs for the polls on an item, which are all structured the same
+// but with different values, and are converted to a more specific type.
+#[derive(Debug, Deserialize)]
+struct XmlPoll {
+    name: String,
+    #[serde(rename = "totalvotes")]
+    total_votes: u64,
+    #[serde(default, rename = "$value")]
+    results: Vec<XmlPollResults>,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlPollResults {
+    #[serde(rename = "numplayers")]
+    player_count: Option<String>,
+    #[serde(default, rename = "$value")]
+    results: Vec<XmlPollResult>,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlPollResult {
+    value: String,
+    #[serde(rename = "numvotes")]
+    votes: u64,
+}
+
+impl TryFrom<XmlPoll> for PlayerCountPoll {
+    type Error = String;
+
+    fn try_from(poll: XmlPoll) -> core::result::Result<Self, Self::Error> {
+        let mut results = Vec::with_capacity(poll.results.len());
+        for poll_results in poll.results {
+            let player_count = poll_results
+                .player_count
+                .ok_or("missing numplayers in player count poll")?;
+            let player_count = match player_count.strip_suffix('+') {
+                Some(count) => count.parse().map(PlayerCount::MoreThan),
+                None => player_count.parse().map(PlayerCount::Exactly),
+            }
+            .map_err(|e| format!("failed to parse numplayers {player_count}: {e}"))?;
+
+            let mut result = PlayerCountPollResult {
+                player_count,
+                best: 0,
+                recommended: 0,
+                not_recommended: 0,
+            };
+            for vote in poll_results.results {
+                match vote.value.as_str() {
+                    "Best" => result.best = vote.votes,
+                    "Recommended" => result.recommended = vote.votes,
+                    "Not Recommended" => result.not_recommended = vote.votes,
+                    other => return Err(format!("unknown player count poll value: {other}")),
+                }
+            }
+            results.push(result);
+        }
+        Ok(Self {
+            total_votes: poll.total_votes,
+            results,
+        })
+    }
+}
+
+/// A link from an item to another related item, such as a game's designer or
+/// one of its expansions.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Link {
+    /// The type of the linked item.
+    #[serde(rename = "type")]
+    pub link_type: LinkType,
+    /// The ID of the linked item.
+    pub id: u64,
+    /// The name of the linked item.
+    #[serde(rename = "value")]
+    pub name: String,
+    /// Whether the link points back from the related item to this one. For example
+    /// the links from a family to the games in it, or from a version to its game,
+    /// are inbound.
+    #[serde(default, deserialize_with = "deserialize_true_false_bool")]
+    pub inbound: bool,
+}
+
+/// The type of item that a [Link] points to.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub enum LinkType {
+    /// A category the game is in, such as "Adventure".
+    #[serde(rename = "boardgamecategory")]
+    Category,
+    /// A mechanic the game uses, such as "Worker Placement".
+    #[serde(rename = "boardgamemechanic")]
+    Mechanic,
+    /// A family of related games, such as "Series: Pandemic".
+    #[serde(rename = "boardgamefamily")]
+    Family,
+    /// An expansion for the game, or if this game is an expansion then the game it expands.
+    #[serde(rename = "boardgameexpansion")]
+    Expansion,
+    /// An accessory for the game.
+    #[serde(rename = "boardgameaccessory")]
+    Accessory,
+    /// A game that reimplements, or is reimplemented by, this game.
+    #[serde(rename = "boardgameimplementation")]
+    Implementation,
+    /// A compilation that contains the game.
+    #[serde(rename = "boardgamecompilation")]
+    Compilation,
+    /// A game that can be integrated with this game.
+    #[serde(rename = "boardgameintegration")]
+    Integration,
+    /// A designer of the game.
+    #[serde(rename = "boardgamedesigner")]
+    Designer,
+    /// An artist who worked on the game.
+    #[serde(rename = "boardgameartist")]
+    Artist,
+    /// A publisher of the game.
+    #[serde(rename = "boardgamepublisher")]
+    Publisher,
+    /// A developer of the game.
+    #[serde(rename = "boardgamedeveloper")]
+    Developer,
+    /// A graphic designer who worked on the game.
+    #[serde(rename = "boardgamegraphicdesigner")]
+    GraphicDesigner,
+    /// A sculptor who worked on the game.
+    #[serde(rename = "boardgamesculptor")]
+    Sculptor,
+    /// An editor who worked on the game.
+    #[serde(rename = "boardgameeditor")]
+    Editor,
+    /// A writer who worked on the game.
+    #[serde(rename = "boardgamewriter")]
+    Writer,
+    /// A designer of the game's insert.
+    #[serde(rename = "boardgameinsertdesigner")]
+    InsertDesigner,
+    /// A designer of the game's solo mode.
+    #[serde(rename = "boardgamesolodesigner")]
+    SoloDesigner,
+    /// A printed version of the game, or if this is a version then the game it is a
+    /// version of.
+    #[serde(rename = "boardgameversion")]
+    Version,
+    /// A language that a version is printed in.
+    #[serde(rename = "language")]
+    Language,
+    /// Any other type of link not listed here.
+    #[serde(other)]
+    Other,
+}
+
+// Intermediary struct needed due to the way the XML is structured.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+struct Statistics {
+    ratings: ThingStats,
+}
+
+/// Stats about the game from all users on the site, such as ratings, ranks,
+/// and how many users own it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThingStats {
+    /// The total number of users who have given this game a rating.
+    pub users_rated: u64,
+    /// The mean average rating for this game.
+    pub average: f64,
+    /// The bayesian average rating for this game.
+    pub bayesian_average: f64,
+    /// The standard deviation of the average rating.
+    pub standard_deviation: f64,
+    // Kept private for now since the API always returns 0 for this seemingly.
+    pub(crate) median: f64,
+    /// The list of ranks the game is on the site within each of its game types.
+    pub ranks: Vec<GameTypeRank>,
+    /// The number of people that own this game.
+    pub owned_by: u64,
+    /// The number of people that want to trade away this game.
+    pub for_trade: u64,
+    /// The number of people that want to receive this game in a trade.
+    pub wanted_in_trade: u64,
+    /// The number of people that have this game on their wishlist.
+    pub wishlisted_by: u64,
+    /// The number of comments users have left on this game.
+    pub number_of_comments: u64,
+    /// The number of users who have voted on the weight of this game.
+    pub number_of_weights: u64,
+    /// The average weight, or complexity, of this game from 1 to 5.
+    pub average_weight: f64,
+}
+
+impl<'de> Deserialize<'de> for ThingStats {
+    fn deserialize<D: serde::de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(field_identifier, rename_all = "lowercase")]
+        enum Field {
+            UsersRated,
+            Average,
+            BayesAverage,
+            StdDev,
+            Median,
+            Ranks,
+            Owned,
+            Trading,
+            Wanting,
+            Wishing,
+            NumComments,
+            NumWeights,
+            AverageWeight,
+            #[serde(other)]
+            Unknown,
+        }
+
+        struct ThingStatsVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ThingStatsVisitor {
+            type Value = ThingStats;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a string containing the XML for the ratings of a thing.")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> core::result::Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut users_rated = None;
+                let mut average = None;
+                let mut bayesian_average = None;
+                let mut standard_deviation = None;
+                let mut median = None;
+                let mut ranks = None;
+                let mut owned_by = None;
+                let mut for_trade = None;
+                let mut wanted_in_trade = None;
+                let mut wishlisted_by = None;
+                let mut number_of_comments = None;
+                let mut number_of_weights = None;
+                let mut average_weight = None;
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Field::UsersRated => {
+                            if users_rated.is_some() {
+                                return Err(serde::de::Error::duplicate_field("usersrated"));
+                            }
+                            let users_rated_xml_tag: XmlIntValue = map.next_value()?;
+                            users_rated = Some(users_rated_xml_tag.value);
+                        }
+                        Field::Average => {
+                            if average.is_some() {
+                                return Err(serde::de::Error::duplicate_field("average"));
+                            }
+                            let average_xml_tag: XmlFloatValue = map.next_value()?;
+                            average = Some(average_xml_tag.value);
+                        }
+                        Field::BayesAverage => {
+                            if bayesian_average.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bayesaverage"));
+                            }
+                            let bayesian_average_xml_tag: XmlFloatValue = map.next_value()?;
+                            bayesian_average = Some(bayesian_average_xml_tag.value);
+                        }
+                        Field::StdDev => {
+                            if standard_deviation.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stddev"));
+                            }
+                            let standard_deviation_xml_tag: XmlFloatValue = map.next_value()?;
+                            standard_deviation = Some(standard_deviation_xml_tag.value);
+                        }
+                        Field::Median => {
+                            if median.is_some() {
+                                return Err(serde::de::Error::duplicate_field("median"));
+                            }
+                            let median_xml_tag: XmlFloatValue = map.next_value()?;
+                            median = Some(median_xml_tag.value);
+                        }
+                        Field::Ranks => {
+                            if ranks.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ranks"));
+                            }
+                            // An extra layer of indirection is needed due to the way the XML is structured,
+                            // but should be removed for the final structure.
+                            let ranks_struct: Ranks = map.next_value()?;
+                            ranks = Some(ranks_struct.ranks);
+                        }
+                        Field::Owned => {
+                            if owned_by.is_some() {
+                                return Err(serde::de::Error::duplicate_field("owned"));
+                            }
+                            let owned_xml_tag: XmlIntValue = map.next_value()?;
+                            owned_by = Some(owned_xml_tag.value);
+                        }
+                        Field::Trading => {
+                            if for_trade.is_some() {
+                                return Err(serde::de::Error::duplicate_field("trading"));
+                            }
+                            let trading_xml_tag: XmlIntValue = map.next_value()?;
+                            for_trade = Some(trading_xml_tag.value);
+                        }
+                        Field::Wanting => {
+                            if wanted_in_trade.is_some() {
+                                return Err(serde::de::Error::duplicate_field("wanting"));
+                            }
+                            let wanting_xml_tag: XmlIntValue = map.next_value()?;
+                            wanted_in_trade = Some(wanting_xml_tag.value);
+                        }
+                        Field::Wishing => {
+                            if wishlisted_by.is_some() {
+                                return Err(serde::de::Error::duplicate_field("wishing"));
+                            }
+                            let wishing_xml_tag: XmlIntValue = map.next_value()?;
+                            wishlisted_by = Some(wishing_xml_tag.value);
+                        }
+                        Field::NumComments => {
+                            if number_of_comments.is_some() {
+                                return Err(serde::de::Error::duplicate_field("numcomments"));
+                            }
+                            let number_of_comments_xml_tag: XmlIntValue = map.next_value()?;
+                            number_of_comments = Some(number_of_comments_xml_tag.value);
+                        }
+                        Field::NumWeights => {
+                            if number_of_weights.is_some() {
+                                return Err(serde::de::Error::duplicate_field("numweights"));
+                            }
+                            let number_of_weights_xml_tag: XmlIntValue = map.next_value()?;
+                            number_of_weights = Some(number_of_weights_xml_tag.value);
+                        }
+                        Field::AverageWeight => {
+                            if average_weight.is_some() {
+                                return Err(serde::de::Error::duplicate_field("averageweight"));
+                            }
+                            let average_weight_xml_tag: XmlFloatValue = map.next_value()?;
+                            average_weight = Some(average_weight_xml_tag.value);
+                        }
+                        Field::Unknown => {
+                            map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                let users_rated =
+                    users_rated.ok_or_else(|| serde::de::Error::missing_field("usersrated"))?;
+                let average = average.ok_or_else(|| serde::de::Error::missing_field("average"))?;
+                let bayesian_average = bayesian_average
+                    .ok_or_else(|| serde::de::Error::missing_field("bayesaverage"))?;
+                let standard_deviation =
+                    standard_deviation.ok_or_else(|| serde::de::Error::missing_field("stddev"))?;
+                let median = median.ok_or_else(|| serde::de::Error::missing_field("median"))?;
+                let ranks = ranks.ok_or_else(|| serde::de::Error::missing_field("ranks"))?;
+                let owned_by = owned_by.ok_or_else(|| serde::de::Error::missing_field("owned"))?;
+                let for_trade =
+                    for_trade.ok_or_else(|| serde::de::Error::missing_field("trading"))?;
+                let wanted_in_trade =
+                    wanted_in_trade.ok_or_else(|| serde::de::Error::missing_field("wanting"))?;
+                let wishlisted_by =
+                    wishlisted_by.ok_or_else(|| serde::de::Error::missing_field("wishing"))?;
+                let number_of_comments = number_of_comments
+                    .ok_or_else(|| serde::de::Error::missing_field("numcomments"))?;
+                let number_of_weights = number_of_weights
+                    .ok_or_else(|| serde::de::Error::missing_field("numweights"))?;
+                let average_weight = average_weight
+                    .ok_or_else(|| serde::de::Error::missing_field("averageweight"))?;
+                Ok(Self::Value {
+                    users_rated,
+                    average,
+                    bayesian_average,
+                    standard_deviation,
+                    median,
+                    ranks,
+                    owned_by,
+                    for_trade,
+                    wanted_in_trade,
+                    wishlisted_by,
+                    number_of_comments,
+                    number_of_weights,
+                    average_weight,
+                })
+            }
+        }
+        deserializer.deserialize_any(ThingStatsVisitor)
+    }
+}
+
+/// Required query paramters.
+#[derive(Clone, Debug)]
+pub struct BaseThingQuery<'q> {
+    pub(crate) ids: &'q [u64],
+}
+
+/// All optional query parameters for making a request to the
+/// thing endpoint.
+#[derive(Clone, Debug, Default)]
+pub struct ThingQueryParams {
+    /// Include only results for this item type.
+    item_type: Option<ThingType>,
+    /// Include stats about the game, such as ratings and ranks, if true.
+    include_stats: Option<bool>,
+    /// Include all printed versions of the game, if true.
+    include_versions: Option<bool>,
+    /// Include a page of user comments on the game, if true.
+    include_comments: Option<bool>,
+    /// Include a page of user ratings on the game, with any comments, if true.
+    include_rating_comments: Option<bool>,
+    /// The page of comments or ratings to return, starting from 1.
+    page: Option<u64>,
+    /// The number of comments or ratings to return per page, between 10 and 100.
+    page_size: Option<u64>,
+    /// Include the listings for the game on the marketplace, if true.
+    include_marketplace: Option<bool>,
+}
+
+impl ThingQueryParams {
+    /// Constructs a new thing query with parameters set to None.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the item_type field, so that only that type of item will be returned.
+    pub fn item_type(mut self, item_type: ThingType) -> Self {
+        self.item_type = Some(item_type);
+        self
+    }
+
+    /// Sets the include_stats field. If true the stats will be included, such as
+    /// ratings, ranks and the number of users who own the game.
+    pub fn include_stats(mut self, include_stats: bool) -> Self {
+        self.include_stats = Some(include_stats);
+        self
+    }
+
+    /// Sets the include_versions field. If true all of the printed versions of the
+    /// game will be included, with their publishers, languages and box dimensions.
+    pub fn include_versions(mut self, include_versions: bool) -> Self {
+        self.include_versions = Some(include_versions);
+        self
+    }
+
+    /// Sets the include_comments field. If true a page of the comments users have
+    /// left on the game will be included, along with any rating they gave.
+    pub fn include_comments(mut self, include_comments: bool) -> Self {
+        self.include_comments = Some(include_comments);
+        self
+    }
+
+    /// Sets the include_rating_comments field. If true a page of the ratings users
+    /// have given the game will be included, along with any comment they left.
+    /// Takes precedence over include_comments if both are set.
+    pub fn include_rating_comments(mut self, include_rating_comments: bool) -> Self {
+        self.include_rating_comments = Some(include_rating_comments);
+        self
+    }
+
+    /// Sets the page field, for which page of comments or ratings to return.
+    /// Pages start from 1.
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the page_size field, for how many comments or ratings to return per page.
+    /// The API accepts values between 10 and 100, and defaults to 100.
+    pub fn page_size(mut self, page_size: u64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the include_marketplace field. If true the listings for the game on
+    /// the marketplace will be included, with their prices and conditions.
+    pub fn include_marketplace(mut self, include_marketplace: bool) -> Self {
+        self.include_marketplace = Some(include_marketplace);
+        self
+    }
+}
+
+/// Struct for building a query for the request to the thing endpoint.
+#[derive(Clone, Debug)]
+struct ThingQueryBuilder<'q> {
+    base: BaseThingQuery<'q>,
+    params: ThingQueryParams,
+}
+
+impl<'builder> ThingQueryBuilder<'builder> {
+    /// Constructs a new query builder from a base query, and the rest of the parameters.
+    fn new(base: BaseThingQuery<'builder>, params: ThingQueryParams) -> Self {
+        Self { base, params }
+    }
+
+    pub fn build(self) -> Vec<(&'builder str, String)> {
+        let mut query_params: Vec<_> = vec![];
+        let ids: Vec<String> = self.base.ids.iter().map(u64::to_string).collect();
+        query_params.push(("id", ids.join(",")));
+
+        if let Some(item_type) = self.params.item_type {
+            query_params.push(("type", item_type.to_string()));
+        }
+        match self.params.include_stats {
+            Some(true) => query_params.push(("stats", "1".to_string())),
+            Some(false) => query_params.push(("stats", "0".to_string())),
+            None => {}
+        }
+        match self.params.include_versions {
+            Some(true) => query_params.push(("versions", "1".to_string())),
+            Some(false) => query_params.push(("versions", "0".to_string())),
+            None => {}
+        }
+        match self.params.include_comments {
+            Some(true) => query_params.push(("comments", "1".to_string())),
+            Some(false) => query_params.push(("comments", "0".to_string())),
+            None => {}
+        }
+        match self.params.include_rating_comments {
+            Some(true) => query_params.push(("ratingcomments", "1".to_string())),
+            Some(false) => query_params.push(("ratingcomments", "0".to_string())),
+            None => {}
+        }
+        if let Some(page) = self.params.page {
+            query_params.push(("page", page.to_string()));
+        }
+        if let Some(page_size) = self.params.page_size {
+            query_params.push(("pagesize", page_size.to_string()));
+        }
+        match self.params.include_marketplace {
+            Some(true) => query_params.push(("marketplace", "1".to_string())),
+            Some(false) => query_params.push(("marketplace", "0".to_string())),
+            None => {}
+        }
+        query_params
+    }
+}
+
+/// Thing endpoint of the API. Used for returning the full details of
+/// games and expansions by their ID.
+pub struct ThingApi<'api> {
+    pub(crate) api: &'api BoardGameGeekApi,
+    endpoint: &'static str,
+}
+
+impl<'api> ThingApi<'api> {
+    // The maximum number of IDs the API accepts in a single request.
+    const MAX_IDS_PER_REQUEST: usize = 20;
+    // The maximum number of requests that will be in flight at once when getting
+    // more IDs than fit in a single request.
+    const MAX_CONCURRENT_REQUESTS: usize = 4;
+    // The number of comments returned per page if not specified, also the maximum.
+    const DEFAULT_COMMENTS_PAGE_SIZE: u64 = 100;
+
+    pub(crate) fn new(api: &'api BoardGameGeekApi) -> Self {
+        Self {
+            api,
+            endpoint: "thing",
+        }
+    }
+
+    /// Gets the full details of a game, including its stats.
+    pub async fn get(&self, id: u64) -> Result<Thing> {
+        let query_params = ThingQueryParams::new().include_stats(true);
+        self.get_from_query(id, query_params).await
+    }
+
+    /// Makes a request for a single game from a [ThingQueryParams].
+    pub async fn get_from_query(&self, id: u64, query_params: ThingQueryParams) -> Result<Thing> {
+        let ids = [id];
+        let query = ThingQueryBuilder::new(BaseThingQuery { ids: &ids }, query_params);
+
+        let request = self.api.build_request(self.endpoint, &query.build());
+        let things = self.api.execute_request::<Things>(request).await?;
+        things
+            .items
+            .into_iter()
+            .find(|thing| thing.id == id)
+            .ok_or(Error::ItemNotFoundError(id))
+    }
+
+    /// Gets the full details of many games, including their stats.
+    ///
+    /// See [ThingApi::get_many_from_query] for how the requests are made.
+    pub async fn get_many(&self, ids: &[u64]) -> Result<ThingBatch> {
+        let query_params = ThingQueryParams::new().include_stats(true);
+        self.get_many_from_query(ids, query_params).await
+    }
+
+    /// Makes a request for many games from a [ThingQueryParams].
+    ///
+    /// The API limits how many IDs can be requested at once, so the IDs are split
+    /// into chunks which are requested a few at a time. The results are returned in
+    /// the same order as the requested IDs, and any IDs the API did not return a
+    /// result for are listed in [ThingBatch::missing_ids]. If any of the requests fail
+    /// then the first error is returned.
+    pub async fn get_many_from_query(
+        &self,
+        ids: &[u64],
+        query_params: ThingQueryParams,
+    ) -> Result<ThingBatch> {
+        let mut seen = HashSet::new();
+        let unique_ids: Vec<u64> = ids.iter().copied().filter(|id| seen.insert(*id)).collect();
+
+        let responses: Vec<Things> = stream::iter(unique_ids.chunks(Self::MAX_IDS_PER_REQUEST))
+            .map(|chunk| {
+                let query =
+                    ThingQueryBuilder::new(BaseThingQuery { ids: chunk }, query_params.clone());
+                let request = self.api.build_request(self.endpoint, &query.build());
+                self.api.execute_request::<Things>(request)
+            })
+            .buffered(Self::MAX_CONCURRENT_REQUESTS)
+            .try_collect()
+            .await?;
+
+        let mut things_by_id: HashMap<u64, Thing> = responses
+            .into_iter()
+            .flat_map(|things| things.items)
+            .map(|thing| (thing.id, thing))
+            .collect();
+        let mut batch = ThingBatch {
+            items: Vec::with_capacity(unique_ids.len()),
+            missing_ids: vec![],
+        };
+        for id in unique_ids {
+            match things_by_id.remove(&id) {
+                Some(thing) => batch.items.push(thing),
+                None => batch.missing_ids.push(id),
+            }
+        }
+        Ok(batch)
+    }
+
+    /// Gets every comment users have left on a game, along with any rating they gave.
+    ///
+    /// See [ThingApi::comments_from_query] for how the pages are requested.
+    pub fn comments(&self, id: u64) -> impl Stream<Item = Result<Comment>> + 'api {
+        let query_params = ThingQueryParams::new().include_comments(true);
+        self.comments_from_query(id, query_params)
+    }
+
+    /// Gets every rating users have given a game, along with any comment they left.
+    ///
+    /// See [ThingApi::comments_from_query] for how the pages are requested.
+    pub fn rating_comments(&self, id: u64) -> impl Stream<Item = Result<Comment>> + 'api {
+        let query_params = ThingQueryParams::new().include_rating_comments(true);
+        self.comments_from_query(id, query_params)
+    }
+
+    /// Gets the comments on a game from a [ThingQueryParams], which should include
+    /// either comments or rating comments.
+    ///
+    /// The comments are returned as a stream which requests each page in turn as
+    /// it is needed, starting from the page in the query, and ends once the total
+    /// number of comments reported by the API have been returned. If a request fails
+    /// the error is returned and the stream ends.
+    pub fn comments_from_query(
+        &self,
+        id: u64,
+        query_params: ThingQueryParams,
+    ) -> impl Stream<Item = Result<Comment>> + 'api {
+        let api = self.api;
+        let page_size = query_params
+            .page_size
+            .unwrap_or(Self::DEFAULT_COMMENTS_PAGE_SIZE);
+        let first_page = query_params.page.unwrap_or(1);
+
+        stream_pages(first_page, page_size, move |page| {
+            let query_params = query_params.clone();
+            async move {
+                let thing = ThingApi::new(api)
+                    .get_from_query(id, query_params.page(page))
+                    .await?;
+                Ok(thing
+                    .comments
+                    .map(|comment_page| (comment_page.comments, comment_page.total_items))
+                    .unwrap_or_default())
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use mockito::Matcher;
+
+    use super::*;
+    use crate::RankValue;
+
+    #[tokio::test]
+    async fn get() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".into(), "312484".into()),
+                Matcher::UrlEncoded("stats".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/thing/thing.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let thing = api.thing().get(312484).await;
+        mock.assert_async().await;
+
+        assert!(thing.is_ok(), "error returned when okay expected");
+        let thing = thing.unwrap();
+
+        assert_eq!(
+            thing,
+            Thing {
+                id: 312484,
+                item_type: ThingType::BoardGame,
+                name: "Lost Ruins of Arnak".into(),
+                alternate_names: vec![
+                    "Arnak".into(),
+                    "Die verlorenen Ruinen von Arnak".into(),
+                ],
+                description: "On an uninhabited island in uncharted seas, explorers have found traces of a great civilization.".into(),
+                image: Some("https://cf.geekdo-images.com/6GqH14TJJhza86BX5HCLEQ__original/img/CDvhKrgkpMOe6OeHLMGhuvaKzzw=/0x0/filters:format(jpeg)/pic5674958.jpg".into()),
+                thumbnail: Some("https://cf.geekdo-images.com/6GqH14TJJhza86BX5HCLEQ__thumb/img/J8SVmGOJXZGxNjkT3xYNQU7Haxg=/fit-in/200x150/filters:strip_icc()/pic5674958.jpg".into()),
+                year_published: Some(2020),
+                min_players: Some(1),
+                max_players: Some(4),
+                suggested_player_count: Some(PlayerCountPoll {
+                    total_votes: 1017,
+                    results: vec![
+                        PlayerCountPollResult {
+                            player_count: PlayerCount::Exactly(1),
+                            best: 71,
+                            recommended: 457,
+                            not_recommended: 277,
+                        },
+                        PlayerCountPollResult {
+                            player_count: PlayerCount::Exactly(2),
+                            best: 310,
+                            recommended: 585,
+                            not_recommended: 51,
+                        },
+                        PlayerCountPollResult {
+                            player_count: PlayerCount::Exactly(3),
+                            best: 766,
+                            recommended: 183,
+                            not_recommended: 9,
+                        },
+                        PlayerCountPollResult {
+                            player_count: PlayerCount::Exactly(4),
+                            best: 370,
+                            recommended: 439,
+                            not_recommended: 104,
+                        },
+                        PlayerCountPollResult {
+                            player_count: PlayerCount::MoreThan(4),
+                            best: 2,
+                            recommended: 5,
+                            not_recommended: 520,
+                        },
+                    ],
+                }),
+                playing_time: Some(Duration::minutes(120)),
+                min_playtime: Some(Duration::minutes(30)),
+                max_playtime: Some(Duration::minutes(120)),
+                min_age: Some(12),
+                links: vec![
+                    Link {
+                        link_type: LinkType::Category,
+                        id: 1022,
+                        name: "Adventure".into(),
+                        inbound: false,
+                    },
+                    Link {
+                        link_type: LinkType::Category,
+                        id: 1020,
+                        name: "Exploration".into(),
+                        inbound: false,
+                    },
+                    Link {
+                        link_type: LinkType::Mechanic,
+                        id: 2664,
+                        name: "Deck, Bag, and Pool Building".into(),
+                        inbound: false,
+                    },
+                    Link {
+                        link_type: LinkType::Mechanic,
+                        id: 2082,
+                        name: "Worker Placement".into(),
+                        inbound: false,
+                    },
+                    Link {
+                        link_type: LinkType::Family,
+                        id: 70360,
+                        name: "Digital Implementations: Board Game Arena".into(),
+                        inbound: false,
+                    },
+                    Link {
+                        link_type: LinkType::Expansion,
+                        id: 341254,
+                        name: "Lost Ruins of Arnak: Expedition Leaders".into(),
+                        inbound: false,
+                    },
+                    Link {
+                        link_type: LinkType::Designer,
+                        id: 127823,
+                        name: "Elwen".into(),
+                        inbound: false,
+                    },
+                    Link {
+                        link_type: LinkType::Designer,
+                        id: 127822,
+                        name: "Mín".into(),
+                        inbound: false,
+                    },
+                    Link {
+                        link_type: LinkType::Publisher,
+                        id: 7345,
+                        name: "Czech Games Edition".into(),
+                        inbound: false,
+                    },
+                ],
+                versions: vec![],
+                comments: None,
+                marketplace_listings: vec![],
+                stats: Some(ThingStats {
+                    users_rated: 48512,
+                    average: 8.06512,
+                    bayesian_average: 7.81426,
+                    standard_deviation: 1.18406,
+                    median: 0.0,
+                    ranks: vec![
+                        GameTypeRank {
+                            game_type: "subtype".into(),
+                            id: 1,
+                            name: "boardgame".into(),
+                            friendly_name: "Board Game Rank".into(),
+                            value: RankValue::Ranked(30),
+                            bayesian_average: 7.81426,
+                        },
+                        GameTypeRank {
+                            game_type: "family".into(),
+                            id: 5497,
+                            name: "strategygames".into(),
+                            friendly_name: "Strategy Game Rank".into(),
+                            value: RankValue::Ranked(31),
+                            bayesian_average: 7.83046,
+                        },
+                    ],
+                    owned_by: 71856,
+                    for_trade: 533,
+                    wanted_in_trade: 1043,
+                    wishlisted_by: 10611,
+                    number_of_comments: 6869,
+                    number_of_weights: 1818,
+                    average_weight: 2.8999,
+                }),
+            },
+        );
+    }
+
+    #[tokio::test]
+    async fn get_rpg_item() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".into(), "171604".into()),
+                Matcher::UrlEncoded("stats".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/thing/thing_rpg_item.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let thing = api.thing().get(171604).await;
+        mock.assert_async().await;
+
+        assert!(thing.is_ok(), "error returned when okay expected");
+        assert_eq!(
+            thing.unwrap(),
+            Thing {
+                id: 171604,
+                item_type: ThingType::RpgItem,
+                name: "Lost Mine of Phandelver".into(),
+                alternate_names: vec![],
+                description: "An adventure for characters of levels 1 to 5.".into(),
+                image: Some("https://cf.geekdo-images.com/rpgitem_original/pic2945633.jpg".into()),
+                thumbnail: Some("https://cf.geekdo-images.com/rpgitem_thumb/pic2945633.jpg".into()),
+                year_published: Some(2014),
+                min_players: None,
+                max_players: None,
+                suggested_player_count: None,
+                playing_time: None,
+                min_playtime: None,
+                max_playtime: None,
+                min_age: None,
+                links: vec![
+                    Link {
+                        link_type: LinkType::Other,
+                        id: 42003,
+                        name: "Dungeons and Dragons (5th Edition)".into(),
+                        inbound: false,
+                    },
+                    Link {
+                        link_type: LinkType::Other,
+                        id: 3,
+                        name: "Wizards of the Coast".into(),
+                        inbound: false,
+                    },
+                ],
+                versions: vec![],
+                comments: None,
+                marketplace_listings: vec![],
+                stats: None,
+            },
+        );
+    }
+
+    #[tokio::test]
+    async fn get_video_game() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".into(), "205093".into()),
+                Matcher::UrlEncoded("stats".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/thing/thing_video_game.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let thing = api.thing().get(205093).await;
+        mock.assert_async().await;
+
+        assert!(thing.is_ok(), "error returned when okay expected");
+        assert_eq!(
+            thing.unwrap(),
+            Thing {
+                id: 205093,
+                item_type: ThingType::VideoGame,
+                name: "Lost Ruins".into(),
+                alternate_names: vec![],
+                description: "A 2D side-scrolling action game set in a mysterious world.".into(),
+                image: None,
+                thumbnail: None,
+                year_published: None,
+                min_players: None,
+                max_players: None,
+                suggested_player_count: None,
+                playing_time: None,
+                min_playtime: None,
+                max_playtime: None,
+                min_age: None,
+                links: vec![
+                    Link {
+                        link_type: LinkType::Other,
+                        id: 16,
+                        name: "Windows".into(),
+                        inbound: false,
+                    },
+                    Link {
+                        link_type: LinkType::Other,
+                        id: 5,
+                        name: "Action".into(),
+                        inbound: false,
+                    },
+                ],
+                versions: vec![],
+                comments: None,
+                marketplace_listings: vec![],
+                stats: None,
+            },
+        );
+    }
+
+    #[test]
+    fn player_count_poll() {
+        let poll = PlayerCountPoll {
+            total_votes: 100,
+            results: vec![
+                PlayerCountPollResult {
+                    player_count: PlayerCount::Exactly(1),
+                    best: 10,
+                    recommended: 30,
+                    not_recommended: 60,
+                },
+                PlayerCountPollResult {
+                    player_count: PlayerCount::Exactly(2),
+                    best: 40,
+                    recommended: 50,
+                    not_recommended: 10,
+                },
+                PlayerCountPollResult {
+                    player_count: PlayerCount::Exactly(3),
+                    best: 80,
+                    recommended: 15,
+                    not_recommended: 5,
+                },
+                PlayerCountPollResult {
+                    player_count: PlayerCount::Exactly(4),
+                    best: 45,
+                    recommended: 35,
+                    not_recommended: 20,
+                },
+                PlayerCountPollResult {
+                    player_count: PlayerCount::MoreThan(4),
+                    best: 60,
+                    recommended: 30,
+                    not_recommended: 10,
+                },
+            ],
+        };
+
+        assert!(!poll.is_best_at(1));
+        assert!(!poll.is_recommended_at(1));
+        assert!(!poll.is_best_at(2));
+        assert!(poll.is_recommended_at(2));
+        assert!(poll.is_best_at(3));
+        assert!(poll.is_recommended_at(3));
+        // Best wins with the most votes, even without a majority.
+        assert!(poll.is_best_at(4));
+        assert!(poll.is_recommended_at(4));
+        // The "4+" entry should not count as any exact player count.
+        assert!(!poll.is_best_at(5));
+        assert!(!poll.is_recommended_at(5));
+        assert_eq!(poll.best_player_counts(), vec![3, 4]);
+        assert_eq!(poll.recommended_player_counts(), vec![2, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn get_with_versions() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".into(), "312484".into()),
+                Matcher::UrlEncoded("versions".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/thing/thing_versions.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let thing = api
+            .thing()
+            .get_from_query(312484, ThingQueryParams::new().include_versions(true))
+            .await;
+        mock.assert_async().await;
+
+        assert!(thing.is_ok(), "error returned when okay expected");
+        let thing = thing.unwrap();
+
+        assert_eq!(thing.stats, None);
+        assert_eq!(thing.versions.len(), 2);
+        assert_eq!(
+            thing.versions[0],
+            Version {
+                id: 536015,
+                name: "English edition".into(),
+                alternate_names: vec![],
+                image: Some("https://cf.geekdo-images.com/6GqH14TJJhza86BX5HCLEQ__original/img/CDvhKrgkpMOe6OeHLMGhuvaKzzw=/0x0/filters:format(jpeg)/pic5674958.jpg".into()),
+                thumbnail: Some("https://cf.geekdo-images.com/6GqH14TJJhza86BX5HCLEQ__thumb/img/J8SVmGOJXZGxNjkT3xYNQU7Haxg=/fit-in/200x150/filters:strip_icc()/pic5674958.jpg".into()),
+                year_published: 2020,
+                product_code: Some("CGE00059".into()),
+                width: Some(11.6),
+                length: Some(11.6),
+                depth: Some(2.9),
+                weight: Some(4.85),
+                links: vec![
+                    Link {
+                        link_type: LinkType::Version,
+                        id: 312484,
+                        name: "Lost Ruins of Arnak".into(),
+                        inbound: true,
+                    },
+                    Link {
+                        link_type: LinkType::Publisher,
+                        id: 7345,
+                        name: "Czech Games Edition".into(),
+                        inbound: false,
+                    },
+                    Link {
+                        link_type: LinkType::Artist,
+                        id: 97446,
+                        name: "Ondřej Hrdina".into(),
+                        inbound: false,
+                    },
+                    Link {
+                        link_type: LinkType::Language,
+                        id: 2184,
+                        name: "English".into(),
+                        inbound: false,
+                    },
+                ],
+            },
+        );
+        assert_eq!(
+            thing.versions[1],
+            Version {
+                id: 541730,
+                name: "German edition".into(),
+                alternate_names: vec!["Die verlorenen Ruinen von Arnak".into()],
+                image: None,
+                thumbnail: None,
+                year_published: 2020,
+                product_code: None,
+                width: None,
+                length: None,
+                depth: None,
+                weight: None,
+                links: vec![
+                    Link {
+                        link_type: LinkType::Version,
+                        id: 312484,
+                        name: "Lost Ruins of Arnak".into(),
+                        inbound: true,
+                    },
+                    Link {
+                        link_type: LinkType::Publisher,
+                        id: 34188,
+                        name: "Heidelbär Games".into(),
+                        inbound: false,
+                    },
+                    Link {
+                        link_type: LinkType::Language,
+                        id: 2188,
+                        name: "German".into(),
+                        inbound: false,
+                    },
+                ],
+            },
+        );
+        let publishers: Vec<&str> = thing.versions[1]
+            .publishers()
+            .map(|link| link.name.as_str())
+            .collect();
+        assert_eq!(publishers, vec!["Heidelbär Games"]);
+        let languages: Vec<&str> = thing.versions[1]
+            .languages()
+            .map(|link| link.name.as_str())
+            .collect();
+        assert_eq!(languages, vec!["German"]);
+    }
+
+    #[tokio::test]
+    async fn get_with_marketplace() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".into(), "312484".into()),
+                Matcher::UrlEncoded("marketplace".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/thing/thing_marketplace.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let thing = api
+            .thing()
+            .get_from_query(312484, ThingQueryParams::new().include_marketplace(true))
+            .await;
+        mock.assert_async().await;
+
+        assert!(thing.is_ok(), "error returned when okay expected");
+        let thing = thing.unwrap();
+
+        assert_eq!(
+            thing.marketplace_listings,
+            vec![
+                MarketplaceListing {
+                    list_date: Utc.with_ymd_and_hms(2020, 12, 12, 14, 41, 26).unwrap(),
+                    price: Price {
+                        minor_units: 4500,
+                        currency: "USD".into(),
+                    },
+                    condition: ItemCondition::New,
+                    notes: "Still in shrink wrap".into(),
+                    link: "https://boardgamegeek.com/market/product/2277137".into(),
+                },
+                MarketplaceListing {
+                    list_date: Utc.with_ymd_and_hms(2024, 3, 4, 9, 5, 11).unwrap(),
+                    price: Price {
+                        minor_units: 3250,
+                        currency: "EUR".into(),
+                    },
+                    condition: ItemCondition::LikeNew,
+                    notes: "".into(),
+                    link: "https://boardgamegeek.com/market/product/3141592".into(),
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn parse_price() {
+        let price = |amount: &str| Price::new(amount, "USD".into()).map(|p| p.minor_units);
+        assert_eq!(price("45.00"), Ok(4500));
+        assert_eq!(price("45.5"), Ok(4550));
+        assert_eq!(price("45"), Ok(4500));
+        assert_eq!(price("0.07"), Ok(7));
+        assert_eq!(price("-3.20"), Ok(-320));
+        assert!(price("45.005").is_err());
+        assert!(price("").is_err());
+        assert!(price(".5").is_err());
+        assert!(price("1e3").is_err());
+        assert_eq!(
+            Price::new("1234.5", "GBP".into()).unwrap().to_string(),
+            "1234.50 GBP"
+        );
+    }
+
+    #[test]
+    fn parse_price_rounded() {
+        let price = |amount: &str| Price::new_rounded(amount, "USD".into()).map(|p| p.minor_units);
+        assert_eq!(price("45.50"), Some(4550));
+        assert_eq!(price(" 45 "), Some(4500));
+        assert_eq!(price("45.004"), Some(4500));
+        assert_eq!(price("45.005"), Some(4501));
+        assert_eq!(price("19.999"), Some(2000));
+        assert_eq!(price("-0.005"), Some(-1));
+        assert_eq!(price("45.00x"), None);
+        assert_eq!(price("$45"), None);
+        assert_eq!(price("45,50"), None);
+    }
+
+    #[tokio::test]
+    async fn get_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "id".into(),
+                "1".into(),
+            )]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/thing/thing_not_found.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let thing = api.thing().get(1).await;
+        mock.assert_async().await;
+
+        assert!(matches!(thing, Err(Error::ItemNotFoundError(1))));
+    }
+
+    #[tokio::test]
+    async fn get_from_query() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".into(), "312484".into()),
+                Matcher::UrlEncoded("type".into(), "boardgame".into()),
+                Matcher::UrlEncoded("stats".into(), "0".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/thing/thing.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let query = ThingQueryParams::new()
+            .item_type(ThingType::BoardGame)
+            .include_stats(false);
+        let thing = api.thing().get_from_query(312484, query).await;
+        mock.assert_async().await;
+
+        assert!(thing.is_ok(), "error returned when okay expected");
+        assert_eq!(thing.unwrap().name, "Lost Ruins of Arnak");
+    }
+
+    #[tokio::test]
+    async fn get_many() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".into(), "312484,1,341254".into()),
+                Matcher::UrlEncoded("stats".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/thing/things_multiple.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let batch = api.thing().get_many(&[312484, 1, 341254, 312484]).await;
+        mock.assert_async().await;
+
+        assert!(batch.is_ok(), "error returned when okay expected");
+        let batch = batch.unwrap();
+
+        let ids: Vec<u64> = batch.items.iter().map(|thing| thing.id).collect();
+        assert_eq!(ids, vec![312484, 341254]);
+        assert_eq!(batch.missing_ids, vec![1]);
+    }
+
+    #[tokio::test]
+    async fn get_many_chunked() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mut ids: Vec<u64> = (1..=19).collect();
+        ids.insert(5, 341254);
+        ids.extend([20, 21, 312484]);
+
+        let first_mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "id".into(),
+                "1,2,3,4,5,341254,6,7,8,9,10,11,12,13,14,15,16,17,18,19".into(),
+            )]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/thing/things_multiple.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+        let second_mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "id".into(),
+                "20,21,312484".into(),
+            )]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/thing/thing.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let batch = api
+            .thing()
+            .get_many_from_query(&ids, ThingQueryParams::new())
+            .await;
+        first_mock.assert_async().await;
+        second_mock.assert_async().await;
+
+        assert!(batch.is_ok(), "error returned when okay expected");
+        let batch = batch.unwrap();
+
+        let found_ids: Vec<u64> = batch.items.iter().map(|thing| thing.id).collect();
+        assert_eq!(found_ids, vec![341254, 312484]);
+        // The first chunk also returned 312484, but it was not requested in that chunk
+        // so should only be returned once.
+        let mut expected_missing: Vec<u64> = (1..=19).collect();
+        expected_missing.extend([20, 21]);
+        assert_eq!(batch.missing_ids, expected_missing);
+    }
+
+    #[tokio::test]
+    async fn comments_from_query() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let first_mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".into(), "312484".into()),
+                Matcher::UrlEncoded("ratingcomments".into(), "1".into()),
+                Matcher::UrlEncoded("page".into(), "1".into()),
+                Matcher::UrlEncoded("pagesize".into(), "2".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/thing/thing_comments_page_1.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+        let second_mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".into(), "312484".into()),
+                Matcher::UrlEncoded("ratingcomments".into(), "1".into()),
+                Matcher::UrlEncoded("page".into(), "2".into()),
+                Matcher::UrlEncoded("pagesize".into(), "2".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/thing/thing_comments_page_2.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let query = ThingQueryParams::new()
+            .include_rating_comments(true)
+            .page_size(2);
+        let comments: Result<Vec<Comment>> = api
+            .thing()
+            .comments_from_query(312484, query)
+            .try_collect()
+            .await;
+        first_mock.assert_async().await;
+        second_mock.assert_async().await;
+
+        assert!(comments.is_ok(), "error returned when okay expected");
+        assert_eq!(
+            comments.unwrap(),
+            vec![
+                Comment {
+                    username: "bluebearbgg".into(),
+                    rating: Some(9.0),
+                    text: "Great deck building and worker placement combo.".into(),
+                },
+                Comment {
+                    username: "someone".into(),
+                    rating: None,
+                    text: "Want to try the expansion.".into(),
+                },
+                Comment {
+                    username: "another_user".into(),
+                    rating: Some(7.5),
+                    text: "".into(),
+                },
+            ],
+        );
+    }
+
+    #[tokio::test]
+    async fn comments_error() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".into(), "312484".into()),
+                Matcher::UrlEncoded("comments".into(), "1".into()),
+                Matcher::UrlEncoded("page".into(), "1".into()),
+            ]))
+            .with_status(500)
+            .create_async()
+            .await;
+
+        let comments: Vec<Result<Comment>> = api.thing().comments(312484).collect().await;
+        mock.assert_async().await;
+
+        assert_eq!(comments.len(), 1);
+        assert!(matches!(comments[0], Err(Error::HttpError(_))));
+    }
+}
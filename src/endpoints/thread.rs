@@ -0,0 +1,253 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::Deserialize;
+
+use crate::utils::rfc3339_date_deserializer;
+use crate::{BoardGameGeekApi, Result};
+
+/// A forum thread, along with the articles posted in it.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Thread {
+    /// The ID of the thread.
+    pub id: u64,
+    /// The total number of articles in the thread, including any not returned due to
+    /// the query parameters.
+    #[serde(rename = "numarticles")]
+    pub number_of_articles: u64,
+    /// A link to the thread on the site.
+    pub link: String,
+    /// The subject of the thread.
+    pub subject: String,
+    /// The articles in the thread, in the order they were posted.
+    #[serde(default, deserialize_with = "deserialize_articles")]
+    pub articles: Vec<Article>,
+}
+
+/// A single post in a [Thread].
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Article {
+    /// The ID of the article.
+    pub id: u64,
+    /// The username of the author of the article.
+    pub username: String,
+    /// A link to the article on the site.
+    pub link: String,
+    /// The date and time the article was posted.
+    #[serde(rename = "postdate", with = "rfc3339_date_deserializer")]
+    pub post_date: DateTime<Utc>,
+    /// The date and time the article was last edited. This is the same as the post
+    /// date if it has never been edited.
+    #[serde(rename = "editdate", with = "rfc3339_date_deserializer")]
+    pub edit_date: DateTime<Utc>,
+    /// The number of times the article has been edited.
+    #[serde(rename = "numedits")]
+    pub number_of_edits: u64,
+    /// The subject of the article.
+    pub subject: String,
+    /// The body of the article, as HTML.
+    pub body: String,
+}
+
+// Intermediary struct needed due to the way the XML is structured.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+struct Articles {
+    #[serde(default, rename = "$value")]
+    articles: Vec<Article>,
+}
+
+fn deserialize_articles<'de, D>(deserializer: D) -> core::result::Result<Vec<Article>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let articles: Articles = Deserialize::deserialize(deserializer)?;
+    Ok(articles.articles)
+}
+
+/// Required query paramters.
+#[derive(Clone, Debug)]
+pub struct BaseThreadQuery {
+    pub(crate) id: u64,
+}
+
+/// All optional query parameters for making a request to the
+/// thread endpoint.
+#[derive(Clone, Debug, Default)]
+pub struct ThreadQueryParams {
+    /// Only include articles with this ID or higher.
+    min_article_id: Option<u64>,
+    /// Only include articles posted at or after this date and time.
+    min_article_date: Option<NaiveDateTime>,
+    /// The maximum number of articles to return.
+    count: Option<u64>,
+}
+
+impl ThreadQueryParams {
+    /// Constructs a new thread query with parameters set to None.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the min_article_id field, so that only articles with this ID or higher
+    /// are returned.
+    pub fn min_article_id(mut self, min_article_id: u64) -> Self {
+        self.min_article_id = Some(min_article_id);
+        self
+    }
+
+    /// Sets the min_article_date field, so that only articles posted at or after this
+    /// date and time are returned.
+    pub fn min_article_date(mut self, min_article_date: NaiveDateTime) -> Self {
+        self.min_article_date = Some(min_article_date);
+        self
+    }
+
+    /// Sets the count field, for the maximum number of articles to return.
+    pub fn count(mut self, count: u64) -> Self {
+        self.count = Some(count);
+        self
+    }
+}
+
+/// Struct for building a query for the request to the thread endpoint.
+#[derive(Clone, Debug)]
+struct ThreadQueryBuilder {
+    base: BaseThreadQuery,
+    params: ThreadQueryParams,
+}
+
+impl ThreadQueryBuilder {
+    /// Constructs a new query builder from a base query, and the rest of the parameters.
+    fn new(base: BaseThreadQuery, params: ThreadQueryParams) -> Self {
+        Self { base, params }
+    }
+
+    pub fn build(self) -> Vec<(&'static str, String)> {
+        let mut query_params: Vec<_> = vec![];
+        query_params.push(("id", self.base.id.to_string()));
+
+        if let Some(min_article_id) = self.params.min_article_id {
+            query_params.push(("minarticleid", min_article_id.to_string()));
+        }
+        if let Some(min_article_date) = self.params.min_article_date {
+            query_params.push((
+                "minarticledate",
+                min_article_date.format("%Y-%m-%d %H:%M:%S").to_string(),
+            ));
+        }
+        if let Some(count) = self.params.count {
+            query_params.push(("count", count.to_string()));
+        }
+        query_params
+    }
+}
+
+/// Thread endpoint of the API. Used for returning the articles posted in a forum
+/// thread.
+pub struct ThreadApi<'api> {
+    pub(crate) api: &'api BoardGameGeekApi,
+    endpoint: &'static str,
+}
+
+impl<'api> ThreadApi<'api> {
+    pub(crate) fn new(api: &'api BoardGameGeekApi) -> Self {
+        Self {
+            api,
+            endpoint: "thread",
+        }
+    }
+
+    /// Gets a thread by its ID, along with all of its articles.
+    pub async fn get(&self, id: u64) -> Result<Thread> {
+        self.get_from_query(id, ThreadQueryParams::new()).await
+    }
+
+    /// Makes a request for a thread from a [ThreadQueryParams].
+    pub async fn get_from_query(&self, id: u64, query_params: ThreadQueryParams) -> Result<Thread> {
+        let query = ThreadQueryBuilder::new(BaseThreadQuery { id }, query_params);
+
+        let request = self.api.build_request(self.endpoint, &query.build());
+        self.api.execute_request::<Thread>(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, TimeZone};
+    use mockito::Matcher;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn get_from_query() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/thread")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".into(), "3254021".into()),
+                Matcher::UrlEncoded("minarticleid".into(), "44012345".into()),
+                Matcher::UrlEncoded("minarticledate".into(), "2024-04-01 00:00:00".into()),
+                Matcher::UrlEncoded("count".into(), "10".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/thread/thread.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let query = ThreadQueryParams::new()
+            .min_article_id(44012345)
+            .min_article_date(
+                NaiveDate::from_ymd_opt(2024, 4, 1)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap(),
+            )
+            .count(10);
+        let thread = api.thread().get_from_query(3254021, query).await;
+        mock.assert_async().await;
+
+        assert!(thread.is_ok(), "error returned when okay expected");
+        assert_eq!(
+            thread.unwrap(),
+            Thread {
+                id: 3254021,
+                number_of_articles: 2,
+                link: "https://boardgamegeek.com/thread/3254021".into(),
+                subject: "Can you use a guardian's boon on the turn you defeat it?".into(),
+                articles: vec![
+                    Article {
+                        id: 44012345,
+                        username: "cardboardsam".into(),
+                        link: "https://boardgamegeek.com/thread/3254021/article/44012345#44012345"
+                            .into(),
+                        post_date: Utc.with_ymd_and_hms(2024, 4, 13, 0, 45, 1).unwrap(),
+                        edit_date: Utc.with_ymd_and_hms(2024, 4, 13, 0, 45, 1).unwrap(),
+                        number_of_edits: 0,
+                        subject: "Can you use a guardian's boon on the turn you defeat it?".into(),
+                        body: "I overcame a guardian this turn, can I use its boon straight away?"
+                            .into(),
+                    },
+                    Article {
+                        id: 44012399,
+                        username: "bluebearbgg".into(),
+                        link: "https://boardgamegeek.com/thread/3254021/article/44012399#44012399"
+                            .into(),
+                        post_date: Utc.with_ymd_and_hms(2024, 4, 13, 1, 10, 45).unwrap(),
+                        edit_date: Utc.with_ymd_and_hms(2024, 4, 13, 1, 15, 2).unwrap(),
+                        number_of_edits: 1,
+                        subject: "Re: Can you use a guardian's boon on the turn you defeat it?"
+                            .into(),
+                        body: "Yes, the boon is ready to use as soon as you take the guardian."
+                            .into(),
+                    },
+                ],
+            },
+        );
+    }
+}
@@ -0,0 +1,653 @@
+use chrono::NaiveDate;
+use futures_util::Stream;
+use serde::Deserialize;
+
+use crate::utils::{deserialize_optional_id, stream_pages, XmlStringValue};
+use crate::{BoardGameGeekApi, Error, Result};
+
+/// A user on the site, along with any of the optional lists requested.
+#[derive(Clone, Debug, PartialEq)]
+pub struct User {
+    /// The ID of the user.
+    pub id: u64,
+    /// The username of the user.
+    pub username: String,
+    /// The user's first name.
+    pub first_name: String,
+    /// The user's last name.
+    pub last_name: String,
+    /// A link to the user's avatar image. Omitted if the user has not set an avatar.
+    pub avatar: Option<String>,
+    /// The year the user registered on the site.
+    pub year_registered: u64,
+    /// The date the user last logged in.
+    pub last_login: Option<NaiveDate>,
+    /// The state or province the user lives in, if set.
+    pub state_or_province: Option<String>,
+    /// The country the user lives in, if set.
+    pub country: Option<String>,
+    /// The user's website, if set.
+    pub web_address: Option<String>,
+    /// The number of positive trade ratings the user has received.
+    pub trade_rating: u64,
+    /// A page of the user's buddies. Only included if requested in the query.
+    pub buddies: Option<UserBuddies>,
+    /// A page of the guilds the user is a member of. Only included if requested
+    /// in the query.
+    pub guilds: Option<UserGuilds>,
+    /// The user's personal top list. Only included if requested in the query.
+    pub top: Option<UserItemList>,
+    /// The user's personal hot list. Only included if requested in the query.
+    pub hot: Option<UserItemList>,
+}
+
+/// A page of a user's buddies.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct UserBuddies {
+    /// The total number of buddies the user has across all pages.
+    pub total: u64,
+    /// The page number, starting from 1.
+    pub page: u64,
+    /// The buddies on this page.
+    #[serde(default, rename = "$value")]
+    pub buddies: Vec<Buddy>,
+}
+
+/// Another user who is a buddy of the requested user.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Buddy {
+    /// The ID of the buddy.
+    pub id: u64,
+    /// The username of the buddy.
+    pub name: String,
+}
+
+/// A page of the guilds a user is a member of.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct UserGuilds {
+    /// The total number of guilds the user is a member of across all pages.
+    pub total: u64,
+    /// The page number, starting from 1.
+    pub page: u64,
+    /// The guilds on this page.
+    #[serde(default, rename = "$value")]
+    pub guilds: Vec<UserGuild>,
+}
+
+/// A guild that the requested user is a member of.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct UserGuild {
+    /// The ID of the guild.
+    pub id: u64,
+    /// The name of the guild.
+    pub name: String,
+}
+
+/// A ranked list of items picked by a user, such as their top 10 or hot 10.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct UserItemList {
+    /// The domain of the items in the list.
+    pub domain: UserDomain,
+    /// The items in the list, in ranked order.
+    #[serde(default, rename = "$value")]
+    pub items: Vec<UserListItem>,
+}
+
+/// An item in a user's top or hot list.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct UserListItem {
+    /// The position of the item in the list, starting from 1.
+    pub rank: u64,
+    /// The type of object in the list, such as "thing" for games.
+    #[serde(rename = "type")]
+    pub item_type: String,
+    /// The ID of the item.
+    pub id: u64,
+    /// The name of the item.
+    pub name: String,
+}
+
+/// The domain that a user's top and hot lists are for.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub enum UserDomain {
+    /// Board games.
+    #[serde(rename = "boardgame")]
+    BoardGame,
+    /// Role playing games.
+    #[serde(rename = "rpg")]
+    Rpg,
+    /// Video games.
+    #[serde(rename = "videogame")]
+    VideoGame,
+}
+
+// Intermediary struct needed due to the way the XML is structured. The API returns
+// a user with all fields empty rather than an error when the username is not found.
+#[derive(Debug, Deserialize)]
+struct XmlUser {
+    #[serde(deserialize_with = "deserialize_optional_id")]
+    id: Option<u64>,
+    name: String,
+    #[serde(rename = "firstname")]
+    first_name: XmlStringValue,
+    #[serde(rename = "lastname")]
+    last_name: XmlStringValue,
+    #[serde(rename = "avatarlink")]
+    avatar: XmlStringValue,
+    #[serde(rename = "yearregistered")]
+    year_registered: XmlStringValue,
+    #[serde(rename = "lastlogin")]
+    last_login: XmlStringValue,
+    #[serde(rename = "stateorprovince")]
+    state_or_province: XmlStringValue,
+    country: XmlStringValue,
+    #[serde(rename = "webaddress")]
+    web_address: XmlStringValue,
+    #[serde(rename = "traderating")]
+    trade_rating: XmlStringValue,
+    buddies: Option<UserBuddies>,
+    guilds: Option<UserGuilds>,
+    top: Option<UserItemList>,
+    hot: Option<UserItemList>,
+}
+
+impl TryFrom<XmlUser> for User {
+    type Error = Error;
+
+    fn try_from(user: XmlUser) -> Result<Self> {
+        let id = user.id.ok_or(Error::UnknownUsernameError)?;
+        let non_empty = |value: String| match value.is_empty() {
+            true => None,
+            false => Some(value),
+        };
+
+        let year_registered = user.year_registered.value.parse::<u64>().map_err(|e| {
+            Error::InvalidResponseError(format!("failed to parse year registered as u64: {e}"))
+        })?;
+        let last_login = match non_empty(user.last_login.value) {
+            Some(date) => Some(NaiveDate::parse_from_str(&date, "%Y-%m-%d").map_err(|e| {
+                Error::InvalidResponseError(format!("failed to parse last login date: {e}"))
+            })?),
+            None => None,
+        };
+        let trade_rating = user.trade_rating.value.parse::<u64>().map_err(|e| {
+            Error::InvalidResponseError(format!("failed to parse trade rating as u64: {e}"))
+        })?;
+        // The avatar is "N/A" rather than empty when the user has not set one.
+        let avatar = non_empty(user.avatar.value).filter(|avatar| avatar != "N/A");
+
+        Ok(Self {
+            id,
+            username: user.name,
+            first_name: user.first_name.value,
+            last_name: user.last_name.value,
+            avatar,
+            year_registered,
+            last_login,
+            state_or_province: non_empty(user.state_or_province.value),
+            country: non_empty(user.country.value),
+            web_address: non_empty(user.web_address.value),
+            trade_rating,
+            buddies: user.buddies,
+            guilds: user.guilds,
+            top: user.top,
+            hot: user.hot,
+        })
+    }
+}
+
+/// All optional query parameters for making a request to the
+/// user endpoint.
+#[derive(Clone, Debug, Default)]
+pub struct UserQueryParams {
+    /// Include a page of the user's buddies.
+    include_buddies: Option<bool>,
+    /// Include a page of the guilds the user is a member of.
+    include_guilds: Option<bool>,
+    /// Include the user's hot list.
+    include_hot: Option<bool>,
+    /// Include the user's top list.
+    include_top: Option<bool>,
+    /// The domain of the hot and top lists, board games if not set.
+    domain: Option<UserDomain>,
+    /// The page of buddies and guilds to return, starting from 1.
+    page: Option<u64>,
+}
+
+impl UserQueryParams {
+    /// Constructs a new user query with parameters set to None.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the include_buddies field. If set to true then a page of the user's
+    /// buddies will be included.
+    pub fn include_buddies(mut self, include_buddies: bool) -> Self {
+        self.include_buddies = Some(include_buddies);
+        self
+    }
+
+    /// Sets the include_guilds field. If set to true then a page of the guilds the
+    /// user is a member of will be included.
+    pub fn include_guilds(mut self, include_guilds: bool) -> Self {
+        self.include_guilds = Some(include_guilds);
+        self
+    }
+
+    /// Sets the include_hot field. If set to true then the user's hot list will
+    /// be included.
+    pub fn include_hot(mut self, include_hot: bool) -> Self {
+        self.include_hot = Some(include_hot);
+        self
+    }
+
+    /// Sets the include_top field. If set to true then the user's top list will
+    /// be included.
+    pub fn include_top(mut self, include_top: bool) -> Self {
+        self.include_top = Some(include_top);
+        self
+    }
+
+    /// Sets the domain field, for which domain the hot and top lists are for.
+    pub fn domain(mut self, domain: UserDomain) -> Self {
+        self.domain = Some(domain);
+        self
+    }
+
+    /// Sets the page field, for which page of buddies and guilds to return.
+    /// Pages start from 1.
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
+/// Required query paramters.
+#[derive(Clone, Debug)]
+pub struct BaseUserQuery<'q> {
+    pub(crate) username: &'q str,
+}
+
+/// Struct for building a query for the request to the user endpoint.
+#[derive(Clone, Debug)]
+struct UserQueryBuilder<'q> {
+    base: BaseUserQuery<'q>,
+    params: UserQueryParams,
+}
+
+impl<'builder> UserQueryBuilder<'builder> {
+    /// Constructs a new query builder from a base query, and the rest of the parameters.
+    fn new(base: BaseUserQuery<'builder>, params: UserQueryParams) -> Self {
+        Self { base, params }
+    }
+
+    pub fn build(self) -> Vec<(&'builder str, String)> {
+        let mut query_params: Vec<_> = vec![];
+        query_params.push(("name", self.base.username.to_string()));
+
+        match self.params.include_buddies {
+            Some(true) => query_params.push(("buddies", "1".to_string())),
+            Some(false) => query_params.push(("buddies", "0".to_string())),
+            None => {}
+        }
+        match self.params.include_guilds {
+            Some(true) => query_params.push(("guilds", "1".to_string())),
+            Some(false) => query_params.push(("guilds", "0".to_string())),
+            None => {}
+        }
+        match self.params.include_hot {
+            Some(true) => query_params.push(("hot", "1".to_string())),
+            Some(false) => query_params.push(("hot", "0".to_string())),
+            None => {}
+        }
+        match self.params.include_top {
+            Some(true) => query_params.push(("top", "1".to_string())),
+            Some(false) => query_params.push(("top", "0".to_string())),
+            None => {}
+        }
+        match self.params.domain {
+            Some(UserDomain::BoardGame) => query_params.push(("domain", "boardgame".to_string())),
+            Some(UserDomain::Rpg) => query_params.push(("domain", "rpg".to_string())),
+            Some(UserDomain::VideoGame) => query_params.push(("domain", "videogame".to_string())),
+            None => {}
+        }
+        if let Some(page) = self.params.page {
+            query_params.push(("page", page.to_string()));
+        }
+        query_params
+    }
+}
+
+/// User endpoint of the API. Used for returning a user's profile, along with their
+/// buddies, guilds, and personal top and hot lists.
+pub struct UserApi<'api> {
+    pub(crate) api: &'api BoardGameGeekApi,
+    endpoint: &'static str,
+}
+
+impl<'api> UserApi<'api> {
+    // The number of buddies and guilds the API returns per page.
+    const PAGE_SIZE: u64 = 100;
+
+    pub(crate) fn new(api: &'api BoardGameGeekApi) -> Self {
+        Self {
+            api,
+            endpoint: "user",
+        }
+    }
+
+    /// Gets a user's profile by their username, without any of the optional lists.
+    pub async fn get(&self, username: &str) -> Result<User> {
+        self.get_from_query(username, UserQueryParams::new()).await
+    }
+
+    /// Makes a request for a user from a [UserQueryParams]. Returns
+    /// [Error::UnknownUsernameError] if no user with the username exists.
+    pub async fn get_from_query(
+        &self,
+        username: &str,
+        query_params: UserQueryParams,
+    ) -> Result<User> {
+        let query = UserQueryBuilder::new(BaseUserQuery { username }, query_params);
+
+        let request = self.api.build_request(self.endpoint, &query.build());
+        let user = self.api.execute_request::<XmlUser>(request).await?;
+        User::try_from(user)
+    }
+
+    /// Gets all of a user's buddies.
+    ///
+    /// The buddies are returned as a stream which requests each page in turn as it is
+    /// needed, and ends once the total number of buddies reported by the API have been
+    /// returned. If a request fails the error is returned and the stream ends.
+    pub fn buddies(&self, username: &'api str) -> impl Stream<Item = Result<Buddy>> + 'api {
+        let api = self.api;
+        stream_pages(1, Self::PAGE_SIZE, move |page| async move {
+            let query_params = UserQueryParams::new().include_buddies(true).page(page);
+            let user = UserApi::new(api)
+                .get_from_query(username, query_params)
+                .await?;
+            Ok(user
+                .buddies
+                .map(|buddies| (buddies.buddies, buddies.total))
+                .unwrap_or_default())
+        })
+    }
+
+    /// Gets all of the guilds a user is a member of.
+    ///
+    /// See [UserApi::buddies] for how the pages are requested.
+    pub fn guilds(&self, username: &'api str) -> impl Stream<Item = Result<UserGuild>> + 'api {
+        let api = self.api;
+        stream_pages(1, Self::PAGE_SIZE, move |page| async move {
+            let query_params = UserQueryParams::new().include_guilds(true).page(page);
+            let user = UserApi::new(api)
+                .get_from_query(username, query_params)
+                .await?;
+            Ok(user
+                .guilds
+                .map(|guilds| (guilds.guilds, guilds.total))
+                .unwrap_or_default())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::StreamExt;
+    use mockito::Matcher;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn get() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/user")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("name".into(), "bluebearbgg".into()),
+                Matcher::UrlEncoded("buddies".into(), "1".into()),
+                Matcher::UrlEncoded("guilds".into(), "1".into()),
+                Matcher::UrlEncoded("hot".into(), "1".into()),
+                Matcher::UrlEncoded("top".into(), "1".into()),
+                Matcher::UrlEncoded("domain".into(), "boardgame".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/user/user.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let query = UserQueryParams::new()
+            .include_buddies(true)
+            .include_guilds(true)
+            .include_hot(true)
+            .include_top(true)
+            .domain(UserDomain::BoardGame);
+        let user = api.user().get_from_query("bluebearbgg", query).await;
+        mock.assert_async().await;
+
+        assert!(user.is_ok(), "error returned when okay expected");
+        assert_eq!(
+            user.unwrap(),
+            User {
+                id: 2398714,
+                username: "bluebearbgg".into(),
+                first_name: "Matt".into(),
+                last_name: "Smith".into(),
+                avatar: Some("https://cf.geekdo-static.com/avatars/avatar_id123456.jpg".into()),
+                year_registered: 2019,
+                last_login: Some(NaiveDate::from_ymd_opt(2024, 4, 14).unwrap()),
+                state_or_province: None,
+                country: Some("United Kingdom".into()),
+                web_address: None,
+                trade_rating: 3,
+                buddies: Some(UserBuddies {
+                    total: 101,
+                    page: 1,
+                    buddies: vec![
+                        Buddy {
+                            id: 1234567,
+                            name: "cardboardsam".into(),
+                        },
+                        Buddy {
+                            id: 2345678,
+                            name: "meeplequeen".into(),
+                        },
+                    ],
+                }),
+                guilds: Some(UserGuilds {
+                    total: 1,
+                    page: 1,
+                    guilds: vec![UserGuild {
+                        id: 3422,
+                        name: "Bristol Board Gamers".into(),
+                    }],
+                }),
+                top: Some(UserItemList {
+                    domain: UserDomain::BoardGame,
+                    items: vec![
+                        UserListItem {
+                            rank: 1,
+                            item_type: "thing".into(),
+                            id: 312484,
+                            name: "Lost Ruins of Arnak".into(),
+                        },
+                        UserListItem {
+                            rank: 2,
+                            item_type: "thing".into(),
+                            id: 224517,
+                            name: "Brass: Birmingham".into(),
+                        },
+                    ],
+                }),
+                hot: Some(UserItemList {
+                    domain: UserDomain::BoardGame,
+                    items: vec![UserListItem {
+                        rank: 1,
+                        item_type: "thing".into(),
+                        id: 341254,
+                        name: "Lost Ruins of Arnak: Expedition Leaders".into(),
+                    }],
+                }),
+            },
+        );
+    }
+
+    #[tokio::test]
+    async fn get_unknown_username() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/user")
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "name".into(),
+                "notarealuser".into(),
+            )]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/user/user_not_found.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let user = api.user().get("notarealuser").await;
+        mock.assert_async().await;
+
+        assert!(matches!(user, Err(Error::UnknownUsernameError)));
+    }
+
+    #[tokio::test]
+    async fn buddies() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let first_mock = server
+            .mock("GET", "/user")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("name".into(), "bluebearbgg".into()),
+                Matcher::UrlEncoded("buddies".into(), "1".into()),
+                Matcher::UrlEncoded("page".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/user/user.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+        let second_mock = server
+            .mock("GET", "/user")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("name".into(), "bluebearbgg".into()),
+                Matcher::UrlEncoded("buddies".into(), "1".into()),
+                Matcher::UrlEncoded("page".into(), "2".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/user/user_buddies_page_2.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let buddies: Vec<Result<Buddy>> = api.user().buddies("bluebearbgg").collect().await;
+        first_mock.assert_async().await;
+        second_mock.assert_async().await;
+
+        let names: Vec<String> = buddies
+            .into_iter()
+            .map(|buddy| buddy.unwrap().name)
+            .collect();
+        assert_eq!(names, vec!["cardboardsam", "meeplequeen", "dicetowerfan"]);
+    }
+
+    #[tokio::test]
+    async fn guilds() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let first_mock = server
+            .mock("GET", "/user")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("name".into(), "bluebearbgg".into()),
+                Matcher::UrlEncoded("guilds".into(), "1".into()),
+                Matcher::UrlEncoded("page".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/user/user_guilds_page_1.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+        let second_mock = server
+            .mock("GET", "/user")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("name".into(), "bluebearbgg".into()),
+                Matcher::UrlEncoded("guilds".into(), "1".into()),
+                Matcher::UrlEncoded("page".into(), "2".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/user/user_guilds_page_2.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+        // All 101 guilds have been returned after the second page, so a third page
+        // should never be requested.
+        let third_mock = server
+            .mock("GET", "/user")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("name".into(), "bluebearbgg".into()),
+                Matcher::UrlEncoded("guilds".into(), "1".into()),
+                Matcher::UrlEncoded("page".into(), "3".into()),
+            ]))
+            .expect(0)
+            .create_async()
+            .await;
+
+        let guilds: Vec<Result<UserGuild>> = api.user().guilds("bluebearbgg").collect().await;
+        first_mock.assert_async().await;
+        second_mock.assert_async().await;
+        third_mock.assert_async().await;
+
+        let guilds: Vec<UserGuild> = guilds.into_iter().map(|guild| guild.unwrap()).collect();
+        assert_eq!(
+            guilds,
+            vec![
+                UserGuild {
+                    id: 3422,
+                    name: "Bristol Board Gamers".to_owned(),
+                },
+                UserGuild {
+                    id: 1290,
+                    name: "Solo Gamers Guild".to_owned(),
+                },
+                UserGuild {
+                    id: 2857,
+                    name: "Euro Game Enthusiasts".to_owned(),
+                },
+            ],
+        );
+    }
+}
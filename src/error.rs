@@ -15,20 +15,62 @@ pub enum Error {
     /// An error was returned making the HTTP request, or an error
     /// status code was returned.
     HttpError(reqwest::Error),
+    /// The API rejected the request as unauthorised (401) or forbidden (403),
+    /// such as when the auth token is missing or invalid.
+    UnauthorisedError(reqwest::Error),
+    /// The request changes a user's data, such as logging a play, so requires
+    /// a user to be logged in with [crate::BoardGameGeekApi::login].
+    NotLoggedInError,
     /// An error occured attempting to parse the response from
     /// the API into the expected type.
     UnexpectedResponseError(serde_xml_rs::Error),
+    /// The response from the API parsed, but its contents were not valid for the
+    /// expected type, such as a missing field or a value in the wrong format.
+    InvalidResponseError(String),
     /// The request tried too many times and timed out before the
     /// data was ready to be returned by the API.
     MaxRetryError(u32),
     /// The username requested was not found.
     UnknownUsernameError,
-    /// Invalid value supplied for subtype ([crate::GameType]) query parameter.
+    /// Invalid value supplied for subtype ([crate::ThingType]) query parameter.
     InvalidCollectionItemType,
+    /// No item with the requested ID was returned by the API.
+    ItemNotFoundError(u64),
     /// The API returned a list of errors that we do not recognise.
     UnknownApiErrors(Vec<String>),
 }
 
+impl Error {
+    /// Whether the request that caused the error may succeed if it is tried again later.
+    ///
+    /// This is the case for timeouts and connection errors, when the API is throttling
+    /// requests or is temporarily unavailable, and when the requested data was still
+    /// not ready after retrying.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::HttpError(e) => {
+                e.is_timeout()
+                    || e.is_connect()
+                    || e.status().is_some_and(|status| {
+                        status == reqwest::StatusCode::TOO_MANY_REQUESTS
+                            || status == reqwest::StatusCode::BAD_GATEWAY
+                            || status == reqwest::StatusCode::SERVICE_UNAVAILABLE
+                            || status == reqwest::StatusCode::GATEWAY_TIMEOUT
+                    })
+            }
+            Error::MaxRetryError(_) => true,
+            Error::UnauthorisedError(_)
+            | Error::NotLoggedInError
+            | Error::UnexpectedResponseError(_)
+            | Error::InvalidResponseError(_)
+            | Error::UnknownUsernameError
+            | Error::InvalidCollectionItemType
+            | Error::ItemNotFoundError(_)
+            | Error::UnknownApiErrors(_) => false,
+        }
+    }
+}
+
 impl From<reqwest::Error> for Error {
     fn from(err: reqwest::Error) -> Self {
         Error::HttpError(err)
@@ -45,12 +87,16 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Error::HttpError(e) => write!(f, "error making request: {}", e),
+            Error::UnauthorisedError(e) => write!(f, "request was not authorised: {}", e),
+            Error::NotLoggedInError => write!(f, "request requires a logged in user"),
             Error::UnexpectedResponseError(e) => write!(f, "error parsing output: {}", e),
+            Error::InvalidResponseError(message) => write!(f, "invalid response: {message}"),
             Error::MaxRetryError(retries) => {
                 write!(f, "data still not ready after {retries} retries, aborting")
             }
             Error::UnknownUsernameError => write!(f, "username not found"),
             Error::InvalidCollectionItemType => write!(f, "invalid collection item type provided"),
+            Error::ItemNotFoundError(id) => write!(f, "no item found with ID {id}"),
             Error::UnknownApiErrors(messages) => match messages.len() {
                 0 => write!(f, "got error from API with no message"),
                 1 => write!(f, "got unknown error from API: {}", messages[0]),
@@ -64,10 +110,14 @@ impl StdError for Error {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
         match &self {
             Error::HttpError(e) => Some(e),
+            Error::UnauthorisedError(e) => Some(e),
+            Error::NotLoggedInError => None,
             Error::UnexpectedResponseError(e) => Some(e),
+            Error::InvalidResponseError(_) => None,
             Error::MaxRetryError(_) => None,
             Error::UnknownUsernameError => None,
             Error::InvalidCollectionItemType => None,
+            Error::ItemNotFoundError(_) => None,
             Error::UnknownApiErrors(_) => None,
         }
     }
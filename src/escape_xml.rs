@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 // Function taken from quick-xml: https://docs.rs/quick-xml/latest/src/quick_xml/escapei.rs
 // but changed to only care about ampersands.
-pub(crate) fn escape_xml(xml_str: &str) -> Cow<str> {
+pub(crate) fn escape_xml(xml_str: &str) -> Cow<'_, str> {
     let bytes = xml_str.as_bytes();
     let mut escaped = None;
     let mut iter = bytes.iter();
@@ -28,6 +28,18 @@ pub use endpoints::*;
 mod error;
 pub use error::*;
 
+mod cache;
+pub use cache::*;
+
 mod escape_xml;
 
+mod rate_limiter;
+pub use rate_limiter::*;
+
+mod retry_policy;
+pub use retry_policy::*;
+
+mod session;
+pub use session::*;
+
 mod utils;
@@ -0,0 +1,116 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::time::{sleep_until, Instant};
+
+/// Limits the rate at which requests are made to the API, to avoid being throttled.
+///
+/// This works as a token bucket, allowing a burst of requests to be made at once,
+/// after which requests are spaced out by the interval. With the default burst of 1
+/// this means requests are always at least the interval apart.
+///
+/// Set on the API with [crate::BoardGameGeekApiBuilder::rate_limiter], the limiter is then
+/// shared by all clones of the API, so requests made from different tasks are limited
+/// together.
+#[derive(Debug)]
+pub struct RateLimiter {
+    // The time between each request once the burst has been used up.
+    interval: Duration,
+    // The number of requests that can be made at once.
+    burst: u32,
+    // The time at which the bucket will next be full, if it isn't already.
+    full_at: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    /// Constructs a rate limiter that spaces out requests by at least the interval.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            burst: 1,
+            full_at: Mutex::new(None),
+        }
+    }
+
+    /// Sets the burst field, for the number of requests that can be made at once before
+    /// they are spaced out by the interval. Values less than 1 are treated as 1.
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    // Waits until a request is allowed to be made, and takes a token from the bucket.
+    pub(crate) async fn acquire(&self) {
+        let allowed_at = {
+            let mut full_at = self.full_at.lock().expect("rate limiter lock poisoned");
+            let now = Instant::now();
+            let current = full_at.map_or(now, |full_at| full_at.max(now));
+            // The bucket holds enough tokens for the burst, so a request can be made as
+            // long as the bucket will be full within that many intervals.
+            let allowed_at = current
+                .checked_sub(self.interval * (self.burst - 1))
+                .map_or(now, |allowed_at| allowed_at.max(now));
+            *full_at = Some(current + self.interval);
+            allowed_at
+        };
+        sleep_until(allowed_at).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn acquire() {
+        let rate_limiter = RateLimiter::new(Duration::from_secs(1));
+        let start = Instant::now();
+
+        rate_limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+        rate_limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+        rate_limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::from_secs(2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn acquire_burst() {
+        let rate_limiter = RateLimiter::new(Duration::from_secs(1)).burst(3);
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            rate_limiter.acquire().await;
+        }
+        assert_eq!(start.elapsed(), Duration::ZERO);
+        rate_limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+
+        // After waiting long enough the whole burst is available again.
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        let refilled = Instant::now();
+        for _ in 0..3 {
+            rate_limiter.acquire().await;
+        }
+        assert_eq!(refilled.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn acquire_shared_across_tasks() {
+        let rate_limiter = Arc::new(RateLimiter::new(Duration::from_secs(1)));
+        let start = Instant::now();
+
+        let tasks: Vec<_> = (0..4)
+            .map(|_| {
+                let rate_limiter = Arc::clone(&rate_limiter);
+                tokio::spawn(async move { rate_limiter.acquire().await })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+        assert_eq!(start.elapsed(), Duration::from_secs(3));
+    }
+}
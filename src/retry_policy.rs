@@ -0,0 +1,241 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::StatusCode;
+
+/// Controls how requests are retried when the API responds with a status that means
+/// the request should be tried again later.
+///
+/// The API returns Accepted (202) when the requested data is not ready yet, such as for
+/// collections, and Too Many Requests (429) or Service Unavailable (503) when it is
+/// throttling clients or overloaded. By default all three are retried, up to 5 attempts
+/// in total, waiting 200ms before the first retry and doubling the wait each time.
+///
+/// If the response has a `Retry-After` header, the wait it asks for is used instead, up
+/// to the max_total_wait if set, or a minute otherwise.
+///
+/// Only requests that are safe to repeat, such as those reading from the API, are
+/// retried. Requests that change data on the site, such as logging a play, are sent
+/// once, so that a request which was saved before failing isn't saved twice.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    /// The total number of times to attempt a request, including the first.
+    max_attempts: u32,
+    /// The wait before the first retry, which is doubled for each retry after.
+    base_delay: Duration,
+    /// The fraction, from 0 to 1, that each wait can be randomly reduced by.
+    jitter: f64,
+    /// The most time to spend waiting between attempts in total.
+    max_total_wait: Option<Duration>,
+    /// The response statuses which cause the request to be retried.
+    retry_statuses: Vec<StatusCode>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(200),
+            jitter: 0.0,
+            max_total_wait: None,
+            retry_statuses: vec![
+                StatusCode::ACCEPTED,
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::SERVICE_UNAVAILABLE,
+            ],
+        }
+    }
+}
+
+impl RetryPolicy {
+    // The longest wait asked for by a Retry-After header that will be used, when there
+    // is no max_total_wait set.
+    const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+    /// Constructs a retry policy with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Constructs a retry policy that never retries requests.
+    pub fn never() -> Self {
+        Self::default().max_attempts(1)
+    }
+
+    /// Sets the max_attempts field, for the total number of times to attempt a request,
+    /// including the first. Values less than 1 are treated as 1.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the base_delay field, for the wait before the first retry. The wait is
+    /// doubled for each retry after that.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Sets the jitter field, for the fraction that each wait can be randomly reduced
+    /// by, so that many clients retrying at once are spread out. This is clamped to
+    /// between 0, for no jitter, and 1, for a wait anywhere up to the full delay.
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the max_total_wait field, for the most time to spend waiting between
+    /// attempts in total. A request is not retried if waiting for it would exceed this.
+    pub fn max_total_wait(mut self, max_total_wait: Duration) -> Self {
+        self.max_total_wait = Some(max_total_wait);
+        self
+    }
+
+    /// Sets the retry_statuses field, for the response statuses which cause the
+    /// request to be retried. If Accepted (202) is left out, a response with it is
+    /// returned as [crate::Error::MaxRetryError] straight away.
+    pub fn retry_statuses(mut self, retry_statuses: impl IntoIterator<Item = StatusCode>) -> Self {
+        self.retry_statuses = retry_statuses.into_iter().collect();
+        self
+    }
+
+    // Whether a response with the given status should be retried.
+    pub(crate) fn retries_status(&self, status: StatusCode) -> bool {
+        self.retry_statuses.contains(&status)
+    }
+
+    // Gets how long to wait before the next attempt, given the number of retries
+    // already made and the time already spent waiting. Returns None if the request
+    // should not be retried again.
+    pub(crate) fn next_delay(
+        &self,
+        retries: u32,
+        total_wait: Duration,
+        headers: &HeaderMap,
+    ) -> Option<Duration> {
+        if retries + 1 >= self.max_attempts {
+            return None;
+        }
+        let max_retry_after = self.max_total_wait.unwrap_or(Self::MAX_RETRY_AFTER);
+        let retry_after = retry_after(headers).map(|delay| delay.min(max_retry_after));
+        let delay = retry_after.unwrap_or_else(|| {
+            let backoff = self
+                .base_delay
+                .saturating_mul(2_u32.saturating_pow(retries));
+            match self.jitter > 0.0 {
+                true => backoff.mul_f64(1.0 - self.jitter * rand::thread_rng().gen::<f64>()),
+                false => backoff,
+            }
+        });
+        match self.max_total_wait {
+            Some(max_total_wait) if total_wait + delay > max_total_wait => None,
+            _ => Some(delay),
+        }
+    }
+}
+
+// Gets the wait asked for by a Retry-After header, which is either a number of seconds
+// or the date and time to retry at.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let retry_after = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = retry_after.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let retry_at = DateTime::parse_from_rfc2822(retry_after).ok()?;
+    // A date in the past means the request can be retried straight away.
+    Some(
+        (retry_at.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn next_delay() {
+        let retry_policy = RetryPolicy::new().max_attempts(4);
+        let headers = HeaderMap::new();
+
+        assert_eq!(
+            retry_policy.next_delay(0, Duration::ZERO, &headers),
+            Some(Duration::from_millis(200)),
+        );
+        assert_eq!(
+            retry_policy.next_delay(2, Duration::ZERO, &headers),
+            Some(Duration::from_millis(800)),
+        );
+        assert_eq!(retry_policy.next_delay(3, Duration::ZERO, &headers), None);
+    }
+
+    #[test]
+    fn next_delay_max_total_wait() {
+        let retry_policy = RetryPolicy::new().max_total_wait(Duration::from_millis(600));
+        let headers = HeaderMap::new();
+
+        assert_eq!(
+            retry_policy.next_delay(1, Duration::from_millis(200), &headers),
+            Some(Duration::from_millis(400)),
+        );
+        assert_eq!(
+            retry_policy.next_delay(2, Duration::from_millis(600), &headers),
+            None,
+        );
+    }
+
+    #[test]
+    fn next_delay_jitter() {
+        let retry_policy = RetryPolicy::new().jitter(0.5);
+        let headers = HeaderMap::new();
+
+        for _ in 0..20 {
+            let delay = retry_policy
+                .next_delay(1, Duration::ZERO, &headers)
+                .unwrap();
+            assert!(delay >= Duration::from_millis(200) && delay <= Duration::from_millis(400));
+        }
+    }
+
+    #[test]
+    fn next_delay_retry_after() {
+        let retry_policy = RetryPolicy::new();
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+        assert_eq!(
+            retry_policy.next_delay(0, Duration::ZERO, &headers),
+            Some(Duration::from_secs(7)),
+        );
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(
+            retry_policy.next_delay(0, Duration::ZERO, &headers),
+            Some(Duration::ZERO),
+        );
+    }
+
+    #[test]
+    fn next_delay_retry_after_capped() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("86400"));
+
+        assert_eq!(
+            RetryPolicy::new().next_delay(0, Duration::ZERO, &headers),
+            Some(Duration::from_secs(60)),
+        );
+        assert_eq!(
+            RetryPolicy::new()
+                .max_total_wait(Duration::from_secs(10))
+                .next_delay(0, Duration::ZERO, &headers),
+            Some(Duration::from_secs(10)),
+        );
+    }
+}
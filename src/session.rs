@@ -0,0 +1,193 @@
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use reqwest::header::{HeaderMap, SET_COOKIE};
+use serde::Serialize;
+
+/// A logged in session with Board Game Geek, holding the cookies returned when logging in.
+///
+/// While logged in, the session cookies are sent with every request made by the API,
+/// which allows private information, such as the private info on a user's own
+/// collection, to be returned.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoginSession {
+    /// The username of the user that is logged in.
+    pub username: String,
+    // The name and value of each cookie returned when logging in.
+    pub(crate) cookies: Vec<(String, String)>,
+    /// The time at which the earliest of the session cookies expires, after which
+    /// the user will need to log in again. None if the cookies do not expire.
+    pub expires: Option<DateTime<Utc>>,
+}
+
+impl LoginSession {
+    // Creates a new session from the cookies set in the headers of the response to
+    // a login request, returning None if no cookies were set.
+    pub(crate) fn from_headers(username: &str, headers: &HeaderMap) -> Option<Self> {
+        let now = Utc::now();
+        let mut cookies = vec![];
+        let mut expires: Option<DateTime<Utc>> = None;
+        for header in headers.get_all(SET_COOKIE) {
+            let Ok(header) = header.to_str() else {
+                continue;
+            };
+            let mut parts = header.split(';').map(str::trim);
+            let Some((name, value)) = parts.next().and_then(|cookie| cookie.split_once('=')) else {
+                continue;
+            };
+            // The API clears cookies from any previous session by setting them to deleted.
+            if value.is_empty() || value == "deleted" {
+                continue;
+            }
+            cookies.push((name.to_string(), value.to_string()));
+
+            let cookie_expires = parse_cookie_expiry(parts, now);
+            expires = match (expires, cookie_expires) {
+                (Some(expires), Some(cookie_expires)) => Some(expires.min(cookie_expires)),
+                (expires, cookie_expires) => expires.or(cookie_expires),
+            };
+        }
+        match cookies.is_empty() {
+            true => None,
+            false => Some(Self {
+                username: username.to_string(),
+                cookies,
+                expires,
+            }),
+        }
+    }
+
+    /// Whether the session is still valid, meaning none of the session cookies have
+    /// expired yet.
+    pub fn is_valid(&self) -> bool {
+        match self.expires {
+            None => true,
+            Some(expires) => expires > Utc::now(),
+        }
+    }
+
+    // The value of the cookie header to send with requests made in this session.
+    pub(crate) fn cookie_header(&self) -> String {
+        self.cookies
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+// Gets the expiry time of a cookie from its attributes. Max-Age takes priority over
+// Expires when both are set, as it does in browsers.
+fn parse_cookie_expiry<'a>(
+    attributes: impl Iterator<Item = &'a str>,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let mut max_age = None;
+    let mut expires = None;
+    for attribute in attributes {
+        let Some((name, value)) = attribute.split_once('=') else {
+            continue;
+        };
+        if name.eq_ignore_ascii_case("max-age") {
+            max_age = value
+                .parse::<i64>()
+                .ok()
+                .map(|seconds| now + Duration::seconds(seconds));
+        } else if name.eq_ignore_ascii_case("expires") {
+            // Cookie expiry dates are either in the standard format, such as
+            // "Wed, 13 Nov 2024 18:29:01 GMT", or with dashes between the date parts.
+            expires = NaiveDateTime::parse_from_str(value, "%a, %d %b %Y %H:%M:%S GMT")
+                .or_else(|_| NaiveDateTime::parse_from_str(value, "%a, %d-%b-%Y %H:%M:%S GMT"))
+                .ok()
+                .map(|expires| expires.and_utc());
+        }
+    }
+    max_age.or(expires)
+}
+
+// The body of a request to the login endpoint.
+#[derive(Serialize)]
+pub(crate) struct LoginRequest<'a> {
+    pub(crate) credentials: LoginCredentials<'a>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct LoginCredentials<'a> {
+    pub(crate) username: &'a str,
+    pub(crate) password: &'a str,
+}
+
+// Creates an API making requests to the mock server, with a session as if a user had
+// logged in.
+#[cfg(test)]
+pub(crate) fn logged_in_api(server: &mockito::Server) -> crate::BoardGameGeekApi {
+    crate::BoardGameGeekApi {
+        session: Some(LoginSession {
+            username: "somename".into(),
+            cookies: vec![("SessionID".into(), "xyz789".into())],
+            expires: None,
+        }),
+        ..crate::BoardGameGeekApi::builder()
+            .base_url(server.url())
+            .site_base_url(server.url())
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.append(
+            SET_COOKIE,
+            HeaderValue::from_static("bggpassword=deleted; expires=Thu, 01-Jan-1970 00:00:01 GMT"),
+        );
+        headers.append(
+            SET_COOKIE,
+            HeaderValue::from_static(
+                "bggusername=somename; expires=Wed, 13-Nov-2024 18:29:01 GMT; path=/",
+            ),
+        );
+        headers.append(
+            SET_COOKIE,
+            HeaderValue::from_static(
+                "bggpassword=abc123; expires=Tue, 12 Nov 2024 18:29:01 GMT; path=/",
+            ),
+        );
+        headers.append(
+            SET_COOKIE,
+            HeaderValue::from_static("SessionID=xyz789; path=/; HttpOnly"),
+        );
+
+        let session = LoginSession::from_headers("somename", &headers);
+        assert_eq!(
+            session,
+            Some(LoginSession {
+                username: "somename".into(),
+                cookies: vec![
+                    ("bggusername".into(), "somename".into()),
+                    ("bggpassword".into(), "abc123".into()),
+                    ("SessionID".into(), "xyz789".into()),
+                ],
+                expires: Some(Utc.with_ymd_and_hms(2024, 11, 12, 18, 29, 1).unwrap()),
+            }),
+        );
+        let session = session.unwrap();
+        assert_eq!(
+            session.cookie_header(),
+            "bggusername=somename; bggpassword=abc123; SessionID=xyz789",
+        );
+        assert!(!session.is_valid());
+    }
+
+    #[test]
+    fn from_headers_no_cookies() {
+        let headers = HeaderMap::new();
+        assert_eq!(LoginSession::from_headers("somename", &headers), None);
+    }
+}
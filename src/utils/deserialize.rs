@@ -27,6 +27,29 @@ pub(crate) struct XmlStringValue {
     pub value: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub(crate) struct XmlMinutesValue {
+    #[serde(deserialize_with = "deserialize_minutes")]
+    pub value: Duration,
+}
+
+// A name tag, of which an item can have many. Exactly one should be the primary name
+// and the rest are alternate names, such as translations.
+#[derive(Debug, Deserialize)]
+pub(crate) struct XmlName {
+    #[serde(rename = "type")]
+    pub name_type: NameType,
+    pub value: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub(crate) enum NameType {
+    #[serde(rename = "primary")]
+    Primary,
+    #[serde(rename = "alternate")]
+    Alternate,
+}
+
 pub(crate) fn deserialize_1_0_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
 where
     D: serde::de::Deserializer<'de>,
@@ -40,6 +63,19 @@ where
     }
 }
 
+pub(crate) fn deserialize_true_false_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let s: String = serde::de::Deserialize::deserialize(deserializer)?;
+
+    match s.as_str() {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => Err(serde::de::Error::unknown_variant(&s, &["true", "false"])),
+    }
+}
+
 pub(crate) fn deserialize_rank_value_enum<'de, D>(deserializer: D) -> Result<RankValue, D::Error>
 where
     D: serde::de::Deserializer<'de>,
@@ -141,8 +177,6 @@ impl<'de> Deserialize<'de> for HotItem {
                 let thumbnail =
                     thumbnail.ok_or_else(|| serde::de::Error::missing_field("thumbnail"))?;
                 let name = name.ok_or_else(|| serde::de::Error::missing_field("name"))?;
-                let year_published = year_published
-                    .ok_or_else(|| serde::de::Error::missing_field("yearpublished"))?;
                 Ok(Self::Value {
                     id,
                     rank,
@@ -356,6 +390,82 @@ where
     deserializer.deserialize_any(CollectionItemRatingBriefVisitor)
 }
 
+// Deserializes a 0-10 rating, which is "N/A" when the user has not given a rating.
+pub(crate) fn deserialize_optional_rating<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let s: String = serde::de::Deserialize::deserialize(deserializer)?;
+    match s.as_str() {
+        "N/A" => Ok(None),
+        other => other.parse::<f64>().map(Some).map_err(|e| {
+            serde::de::Error::custom(format!("failed to parse value as N/A or float: {e}"))
+        }),
+    }
+}
+
+// Deserializes a string which the API returns as empty when there is no value.
+pub(crate) fn deserialize_optional_string<'de, D>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let s: String = serde::de::Deserialize::deserialize(deserializer)?;
+    match s.is_empty() {
+        true => Ok(None),
+        false => Ok(Some(s)),
+    }
+}
+
+// Deserializes a number which the API returns as empty when there is no value.
+pub(crate) fn deserialize_optional_u64<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let s: String = serde::de::Deserialize::deserialize(deserializer)?;
+    match s.as_str() {
+        "" => Ok(None),
+        other => other.parse::<u64>().map(Some).map_err(|e| {
+            serde::de::Error::custom(format!("failed to parse value as empty or u64: {e}"))
+        }),
+    }
+}
+
+// Deserializes an ID which the API returns as empty or 0 when there is no value.
+pub(crate) fn deserialize_optional_id<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let s: String = serde::de::Deserialize::deserialize(deserializer)?;
+    match s.as_str() {
+        "" | "0" => Ok(None),
+        other => other.parse::<u64>().map(Some).map_err(|e| {
+            serde::de::Error::custom(format!("failed to parse value as empty or u64: {e}"))
+        }),
+    }
+}
+
+// Deserializes a float which the API returns as empty or 0 when there is no value.
+pub(crate) fn deserialize_optional_nonzero_float<'de, D>(
+    deserializer: D,
+) -> Result<Option<f64>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let s: String = serde::de::Deserialize::deserialize(deserializer)?;
+    if s.is_empty() {
+        return Ok(None);
+    }
+    let value = s.parse::<f64>().map_err(|e| {
+        serde::de::Error::custom(format!("failed to parse value as empty or float: {e}"))
+    })?;
+    match value == 0.0 {
+        true => Ok(None),
+        false => Ok(Some(value)),
+    }
+}
+
 pub(crate) fn deserialize_minutes<'de, D>(deserializer: D) -> Result<Duration, D::Error>
 where
     D: serde::de::Deserializer<'de>,
@@ -404,3 +514,49 @@ pub(crate) mod date_deserializer {
         Ok(DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc))
     }
 }
+
+pub(crate) mod naive_date_deserializer {
+    use chrono::NaiveDate;
+    use serde::{self, Deserialize, Deserializer};
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        NaiveDate::parse_from_str(&s, "%Y-%m-%d").map_err(serde::de::Error::custom)
+    }
+}
+
+pub(crate) mod rfc2822_date_deserializer {
+    use chrono::{DateTime, ParseError, Utc};
+    use serde::{self, Deserialize, Deserializer};
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        parse(&s).map_err(serde::de::Error::custom)
+    }
+
+    // Exposed separately for visitors and conversions that read the date as a plain
+    // string first.
+    pub fn parse(s: &str) -> Result<DateTime<Utc>, ParseError> {
+        Ok(DateTime::parse_from_rfc2822(s)?.with_timezone(&Utc))
+    }
+}
+
+pub(crate) mod rfc3339_date_deserializer {
+    use chrono::{DateTime, Utc};
+    use serde::{self, Deserialize, Deserializer};
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let dt = DateTime::parse_from_rfc3339(&s).map_err(serde::de::Error::custom)?;
+        Ok(dt.with_timezone(&Utc))
+    }
+}
@@ -1,2 +1,4 @@
 pub(crate) mod deserialize;
 pub(crate) use deserialize::*;
+pub(crate) mod pagination;
+pub(crate) use pagination::*;
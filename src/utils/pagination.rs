@@ -0,0 +1,41 @@
+use std::future::Future;
+
+use futures_util::{stream, Stream, TryStreamExt};
+
+use crate::Result;
+
+// Walks the pages of a paged list from the API, starting from first_page, given a
+// function to get the items on a page along with the total number of items in the list.
+//
+// The stream ends once a page comes back empty or the total number of items has been
+// reached, or after returning the error if getting a page fails.
+pub(crate) fn stream_pages<'a, T, F, Fut>(
+    first_page: u64,
+    page_size: u64,
+    get_page: F,
+) -> impl Stream<Item = Result<T>> + 'a
+where
+    T: 'a,
+    F: Fn(u64) -> Fut + 'a,
+    Fut: Future<Output = Result<(Vec<T>, u64)>> + 'a,
+{
+    stream::try_unfold(
+        (Some(first_page), get_page),
+        move |(page, get_page)| async move {
+            match page {
+                Some(page) => get_page(page).await.map(|(items, total)| {
+                    let next_page = match items.is_empty() || page * page_size >= total {
+                        true => None,
+                        false => Some(page + 1),
+                    };
+                    Some((
+                        stream::iter(items.into_iter().map(Ok)),
+                        (next_page, get_page),
+                    ))
+                }),
+                None => Ok(None),
+            }
+        },
+    )
+    .try_flatten()
+}